@@ -479,11 +479,16 @@ impl Coordinator {
                     | Statement::GrantPrivileges(_)
                     | Statement::GrantRole(_)
                     | Statement::Insert(_)
+                    | Statement::Merge(_)
                     | Statement::RevokePrivileges(_)
                     | Statement::AlterDefaultPrivileges(_)
                     | Statement::RevokeRole(_)
                     | Statement::Update(_)
-                    | Statement::ReassignOwned(_) => {
+                    | Statement::ReassignOwned(_)
+                    | Statement::Call(_)
+                    | Statement::Analyze(_)
+                    | Statement::Vacuum(_)
+                    | Statement::Comment(_) => {
                         return tx.send(
                             Err(AdapterError::OperationProhibitsTransaction(
                                 stmt.to_string(),
@@ -96,6 +96,7 @@ where
         StatementKind::Copy => "copy",
         StatementKind::Update => "update",
         StatementKind::Delete => "delete",
+        StatementKind::Merge => "merge",
         StatementKind::CreateConnection => "create_connection",
         StatementKind::CreateDatabase => "create_database",
         StatementKind::CreateSchema => "create_schema",
@@ -141,6 +142,10 @@ where
         StatementKind::Execute => "execute",
         StatementKind::Deallocate => "deallocate",
         StatementKind::Raise => "raise",
+        StatementKind::Call => "call",
+        StatementKind::Analyze => "analyze",
+        StatementKind::Vacuum => "vacuum",
+        StatementKind::Comment => "comment",
         StatementKind::GrantRole => "grant_role",
         StatementKind::RevokeRole => "revoke_role",
         StatementKind::GrantPrivileges => "grant_privileges",
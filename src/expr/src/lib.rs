@@ -110,8 +110,8 @@ pub use relation::{
 };
 pub use scalar::func::{self, BinaryFunc, UnaryFunc, UnmaterializableFunc, VariadicFunc};
 pub use scalar::{
-    like_pattern, EvalError, FilterCharacteristics, MirScalarExpr, ProtoDomainLimit,
-    ProtoEvalError, ProtoMirScalarExpr,
+    like_pattern, EvalError, EvalErrorCategory, FilterCharacteristics, MirScalarExpr,
+    ProtoDomainLimit, ProtoEvalError, ProtoMirScalarExpr,
 };
 
 /// A [`MirRelationExpr`] that claims to have been optimized, e.g., by an
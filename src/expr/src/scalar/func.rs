@@ -60,7 +60,7 @@ use crate::{like_pattern, EvalError, MirScalarExpr};
 
 #[macro_use]
 mod macros;
-mod encoding;
+pub(crate) mod encoding;
 mod format;
 pub(crate) mod impls;
 
@@ -1855,6 +1855,22 @@ fn timezone_interval_timestamptz(a: Datum<'_>, b: Datum<'_>) -> Result<Datum<'st
     }
 }
 
+/// Converts the interval `interval` into a fixed-offset [`Timezone`] that behaves like it does in the
+/// `timezone_interval_*` functions, so that a literal interval only needs to be inspected once.
+/// Returns an error if the interval holds months, and `None` if it is not a whole number of seconds
+/// or falls outside of the `-86,400 < x < 86,400` range that `chrono::FixedOffset` supports.
+pub(crate) fn timezone_from_interval(interval: Interval) -> Option<Result<Timezone, EvalError>> {
+    if interval.months != 0 {
+        return Some(Err(EvalError::InvalidTimezoneInterval));
+    }
+    let duration = interval.duration_as_chrono();
+    if duration.num_microseconds() != Some(duration.num_seconds() * 1_000_000) {
+        return None;
+    }
+    let seconds = i32::try_from(duration.num_seconds()).ok()?;
+    chrono::FixedOffset::east_opt(seconds).map(|offset| Ok(Timezone::FixedOffset(offset)))
+}
+
 /// Determines if an mz_aclitem contains one of the specified privileges. This will return true if
 /// any of the listed privileges are contained in the mz_aclitem.
 fn mz_acl_item_contains_privilege(a: Datum<'_>, b: Datum<'_>) -> Result<Datum<'static>, EvalError> {
@@ -4488,7 +4504,9 @@ derive_unary!(
     MzAclItemGrantor,
     MzAclItemGrantee,
     MzAclItemPrivileges,
-    MzValidatePrivileges
+    MzValidatePrivileges,
+    TryCastStringToInt32,
+    TryCastStringToInt64
 );
 
 impl UnaryFunc {
@@ -4508,9 +4526,34 @@ impl UnaryFunc {
     pub fn could_error(&self) -> bool {
         match self {
             UnaryFunc::IsNull(_) | UnaryFunc::CastVarCharToString(_) | UnaryFunc::Not(_) => false,
+            UnaryFunc::TryCastStringToInt32(_) | UnaryFunc::TryCastStringToInt64(_) => false,
             _ => true,
         }
     }
+
+    /// Returns true if the function casts an integer to a wider integer type. Such a cast can't
+    /// error, and its inverse recovers the input.
+    pub fn is_widening_int_cast(&self) -> bool {
+        matches!(
+            self,
+            UnaryFunc::CastInt16ToInt32(_)
+                | UnaryFunc::CastInt16ToInt64(_)
+                | UnaryFunc::CastInt32ToInt64(_)
+                | UnaryFunc::CastUint16ToUint32(_)
+                | UnaryFunc::CastUint16ToUint64(_)
+                | UnaryFunc::CastUint32ToUint64(_)
+        )
+    }
+
+    /// Returns the variant of this cast that evaluates to null instead of erroring on invalid
+    /// input, if there is one.
+    pub fn try_variant(&self) -> Option<UnaryFunc> {
+        match self {
+            UnaryFunc::CastStringToInt32(_) => Some(TryCastStringToInt32.into()),
+            UnaryFunc::CastStringToInt64(_) => Some(TryCastStringToInt64.into()),
+            _ => None,
+        }
+    }
 }
 
 /// An explicit [`Arbitrary`] implementation needed here because of a known
@@ -4888,6 +4931,8 @@ impl Arbitrary for UnaryFunc {
             MzAclItemGrantee::arbitrary().prop_map_into().boxed(),
             MzAclItemPrivileges::arbitrary().prop_map_into().boxed(),
             MzValidatePrivileges::arbitrary().prop_map_into().boxed(),
+            TryCastStringToInt32::arbitrary().prop_map_into().boxed(),
+            TryCastStringToInt64::arbitrary().prop_map_into().boxed(),
         ])
     }
 }
@@ -5239,6 +5284,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::MzAclItemGrantee(_) => MzAclItemGrantee(()),
             UnaryFunc::MzAclItemPrivileges(_) => MzAclItemPrivileges(()),
             UnaryFunc::MzValidatePrivileges(_) => MzValidatePrivileges(()),
+            UnaryFunc::TryCastStringToInt32(_) => TryCastStringToInt32(()),
+            UnaryFunc::TryCastStringToInt64(_) => TryCastStringToInt64(()),
         };
         ProtoUnaryFunc { kind: Some(kind) }
     }
@@ -5660,6 +5707,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 MzAclItemGrantee(_) => Ok(impls::MzAclItemGrantee.into()),
                 MzAclItemPrivileges(_) => Ok(impls::MzAclItemPrivileges.into()),
                 MzValidatePrivileges(_) => Ok(impls::MzValidatePrivileges.into()),
+                TryCastStringToInt32(_) => Ok(impls::TryCastStringToInt32.into()),
+                TryCastStringToInt64(_) => Ok(impls::TryCastStringToInt64.into()),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoUnaryFunc::kind"))
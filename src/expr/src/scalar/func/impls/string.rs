@@ -88,6 +88,22 @@ sqlfunc!(
     }
 );
 
+sqlfunc!(
+    #[sqlname = "try_text_to_integer"]
+    #[preserves_uniqueness = false]
+    fn try_cast_string_to_int32<'a>(a: &'a str) -> Option<i32> {
+        strconv::parse_int32(a).ok()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "try_text_to_bigint"]
+    #[preserves_uniqueness = false]
+    fn try_cast_string_to_int64<'a>(a: &'a str) -> Option<i64> {
+        strconv::parse_int64(a).ok()
+    }
+);
+
 sqlfunc!(
     #[sqlname = "text_to_real"]
     #[preserves_uniqueness = false]
@@ -23,7 +23,7 @@ use mz_pgrepr::TypeFromOidError;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::InvalidArrayError;
 use mz_repr::adt::date::DateError;
-use mz_repr::adt::datetime::DateTimeUnits;
+use mz_repr::adt::datetime::{DateTimeUnits, Timezone};
 use mz_repr::adt::range::InvalidRangeError;
 use mz_repr::adt::regex::Regex;
 use mz_repr::adt::timestamp::TimestampError;
@@ -270,6 +270,10 @@ impl MirScalarExpr {
         MirScalarExpr::literal_ok(Datum::True, ScalarType::Bool)
     }
 
+    pub fn literal_bytes(bytes: Vec<u8>) -> Self {
+        MirScalarExpr::literal_ok(Datum::Bytes(&bytes), ScalarType::Bytes)
+    }
+
     pub fn call_unary(self, func: UnaryFunc) -> Self {
         MirScalarExpr::CallUnary {
             func,
@@ -325,6 +329,178 @@ impl MirScalarExpr {
         }
     }
 
+    /// Returns the operands of `self` if it is an AND, or `self` otherwise. Nested ANDs are
+    /// flattened, so this doesn't rely on `flatten_associative` having been run.
+    pub fn conjuncts(&self) -> Vec<&MirScalarExpr> {
+        self.flattened_args(&VariadicFunc::And)
+    }
+
+    /// Returns the operands of `self` if it is an OR, or `self` otherwise. Nested ORs are
+    /// flattened, so this doesn't rely on `flatten_associative` having been run.
+    pub fn disjuncts(&self) -> Vec<&MirScalarExpr> {
+        self.flattened_args(&VariadicFunc::Or)
+    }
+
+    /// Collects the operands of nested calls to the associative `func_to_match`, in
+    /// left-to-right order.
+    fn flattened_args(&self, func_to_match: &VariadicFunc) -> Vec<&MirScalarExpr> {
+        let mut args = Vec::new();
+        let mut todo = vec![self];
+        while let Some(expr) = todo.pop() {
+            match expr {
+                MirScalarExpr::CallVariadic { func, exprs } if func == func_to_match => {
+                    todo.extend(exprs.iter().rev())
+                }
+                _ => args.push(expr),
+            }
+        }
+        args
+    }
+
+    /// Collects the atomic predicates of a boolean expression, i.e., the leaves of the tree
+    /// formed by AND, OR, and NOT calls. The atoms are returned in left-to-right order.
+    pub fn atomic_predicates(&self) -> Vec<&MirScalarExpr> {
+        let mut atoms = Vec::new();
+        let mut todo = vec![self];
+        while let Some(expr) = todo.pop() {
+            match expr {
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::And | VariadicFunc::Or,
+                    exprs,
+                } => todo.extend(exprs.iter().rev()),
+                MirScalarExpr::CallUnary {
+                    func: UnaryFunc::Not(func::Not),
+                    expr,
+                } => todo.push(expr),
+                _ => atoms.push(expr),
+            }
+        }
+        atoms
+    }
+
+    /// Reduces `self` and splits it into its top-level conjuncts, dropping any that are
+    /// literally true. A predicate that reduces to `true` yields no conjuncts.
+    pub fn into_reduced_predicates(mut self, column_types: &[ColumnType]) -> Vec<MirScalarExpr> {
+        self.reduce(column_types);
+        let mut predicates = match self {
+            MirScalarExpr::CallVariadic {
+                func: VariadicFunc::And,
+                exprs,
+            } => exprs,
+            predicate => vec![predicate],
+        };
+        predicates.retain(|p| !p.is_literal_true());
+        predicates
+    }
+
+    /// If `self` is a conjunction of equalities between columns, returns the pairs of columns
+    /// that are equated. A single equality is treated as a 1-arg AND. Returns `None` if any
+    /// conjunct is something other than a `Column = Column` equality.
+    pub fn as_equijoin_keys(&self) -> Option<Vec<(usize, usize)>> {
+        self.conjuncts()
+            .into_iter()
+            .map(|conjunct| match conjunct {
+                MirScalarExpr::CallBinary {
+                    func: BinaryFunc::Eq,
+                    expr1,
+                    expr2,
+                } => Some((expr1.as_column()?, expr2.as_column()?)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Groups the columns that the conjuncts of `self` equate into classes of columns that
+    /// must all be equal, following equalities transitively. Conjuncts other than
+    /// `Column = Column` equalities are ignored.
+    ///
+    /// The classes are disjoint, have at least two members each, and are sorted.
+    pub fn column_equivalence_classes(&self) -> Vec<BTreeSet<usize>> {
+        fn find(parents: &mut BTreeMap<usize, usize>, column: usize) -> usize {
+            let mut root = column;
+            while let Some(&parent) = parents.get(&root) {
+                if parent == root {
+                    break;
+                }
+                root = parent;
+            }
+            // Point everything on the path directly at the root.
+            let mut current = column;
+            while current != root {
+                current = parents.insert(current, root).unwrap_or(root);
+            }
+            root
+        }
+
+        let mut parents = BTreeMap::new();
+        for conjunct in self.conjuncts() {
+            if let MirScalarExpr::CallBinary {
+                func: BinaryFunc::Eq,
+                expr1,
+                expr2,
+            } = conjunct
+            {
+                if let (Some(c1), Some(c2)) = (expr1.as_column(), expr2.as_column()) {
+                    let r1 = find(&mut parents, c1);
+                    let r2 = find(&mut parents, c2);
+                    let root = r1.min(r2);
+                    parents.insert(r1, root);
+                    parents.insert(r2, root);
+                }
+            }
+        }
+
+        let mut classes: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+        let columns: Vec<_> = parents.keys().copied().collect();
+        for column in columns {
+            let root = find(&mut parents, column);
+            classes.entry(root).or_default().insert(column);
+        }
+        classes
+            .into_values()
+            .filter(|class| class.len() > 1)
+            .collect()
+    }
+
+    /// Try to match a null-tolerant literal equality, i.e., `<expr> IS NULL OR <expr> = <literal>`
+    /// (with the arguments of the OR and the `=` in either order). Return `<expr>` and the
+    /// (non-null) literal.
+    ///
+    /// Such a predicate can be treated like an equality that also matches NULLs.
+    pub fn as_nullable_equality(&self) -> Option<(MirScalarExpr, Row)> {
+        if let MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Or,
+            exprs,
+        } = self
+        {
+            if let [a, b] = &exprs[..] {
+                for (is_null, eq) in [(a, b), (b, a)] {
+                    if let (
+                        MirScalarExpr::CallUnary {
+                            func: UnaryFunc::IsNull(func::IsNull),
+                            expr,
+                        },
+                        MirScalarExpr::CallBinary {
+                            func: BinaryFunc::Eq,
+                            expr1,
+                            expr2,
+                        },
+                    ) = (is_null, eq)
+                    {
+                        for (e, lit) in [(expr1, expr2), (expr2, expr1)] {
+                            if e == expr && !lit.is_literal_null() {
+                                if let MirScalarExpr::Literal(Ok(row), _) = &**lit {
+                                    return Some(((**expr).clone(), row.clone()));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
     /// Try to match a literal equality involving the given expression on one side.
     /// Return the (non-null) literal and a bool that indicates whether an inversion was needed.
     ///
@@ -531,6 +707,128 @@ impl MirScalarExpr {
         false
     }
 
+    /// Reports whether `self`, interpreted as a predicate, can be seen to never evaluate to
+    /// `true` without looking at any data.
+    ///
+    /// This recognizes
+    ///  - literal `false` and `NULL`,
+    ///  - comparisons between two literals that evaluate to `false` or `NULL`, e.g., `5 = 6`,
+    ///  - `<expr> IS NULL` where `<expr>` is non-nullable and can't error,
+    ///  - equalities that are impossible because of types
+    ///    (see `impossible_literal_equality_because_types`), where the non-literal side is a
+    ///    widening integer cast of an expression that can't error,
+    ///  - conjunctions where some conjunct is impossible, or where an expression that can't
+    ///    error is bounded by literals in a contradictory way, e.g., `#0 < 5 AND #0 > 10`,
+    ///  - disjunctions where every disjunct is impossible.
+    ///
+    /// A predicate that could error is not impossible, as it doesn't evaluate to `false` or
+    /// `NULL` on the inputs where it errors, e.g., `1 / #0 < 5 AND 1 / #0 > 10` for `#0 = 0`.
+    ///
+    /// This is conservative: a `false` return value does not mean that the predicate can be
+    /// satisfied.
+    pub fn is_impossible(&self, column_types: &[ColumnType]) -> bool {
+        let conjuncts = self.conjuncts();
+        if conjuncts.len() > 1 {
+            return conjuncts.iter().any(|c| c.is_impossible(column_types))
+                || Self::contradictory_literal_bounds(&conjuncts);
+        }
+        let disjuncts = self.disjuncts();
+        if disjuncts.len() > 1 {
+            return disjuncts.iter().all(|d| d.is_impossible(column_types));
+        }
+        match self {
+            MirScalarExpr::Literal(Ok(row), _) => {
+                matches!(row.unpack_first(), Datum::False | Datum::Null)
+            }
+            MirScalarExpr::CallUnary {
+                func: UnaryFunc::IsNull(_),
+                expr,
+            } => !expr.typ(column_types).nullable && !expr.could_error(),
+            MirScalarExpr::CallBinary { expr1, expr2, .. }
+                if expr1.is_literal_ok() && expr2.is_literal_ok() =>
+            {
+                let temp_storage = RowArena::new();
+                matches!(
+                    self.eval(&[], &temp_storage),
+                    Ok(Datum::False) | Ok(Datum::Null)
+                )
+            }
+            MirScalarExpr::CallBinary {
+                func: BinaryFunc::Eq,
+                expr1,
+                expr2,
+            } if [expr1, expr2].into_iter().any(|e| {
+                matches!(
+                    &**e,
+                    MirScalarExpr::CallUnary { func, expr }
+                        if func.is_widening_int_cast() && !expr.could_error()
+                )
+            }) =>
+            {
+                self.impossible_literal_equality_because_types()
+            }
+            _ => false,
+        }
+    }
+
+    /// Reports whether `conjuncts` bound some expression from below and from above by literals
+    /// such that no value lies between the bounds, e.g., `#0 < 5 AND #0 > 10` or
+    /// `#0 = 3 AND #0 > 3`.
+    fn contradictory_literal_bounds(conjuncts: &[&MirScalarExpr]) -> bool {
+        // (bounded expression, literal, whether it's a lower bound, whether it's inclusive)
+        let mut bounds = Vec::new();
+        for conjunct in conjuncts {
+            if let MirScalarExpr::CallBinary { func, expr1, expr2 } = conjunct {
+                let (expr, literal, literal_on_left) =
+                    if expr2.is_literal_ok() && !expr1.is_literal() {
+                        (&**expr1, &**expr2, false)
+                    } else if expr1.is_literal_ok() && !expr2.is_literal() {
+                        (&**expr2, &**expr1, true)
+                    } else {
+                        continue;
+                    };
+                if literal.is_literal_null() || expr.could_error() {
+                    continue;
+                }
+                // Bounds for `<expr> <func> <literal>`; flipped below if the literal is on the
+                // left.
+                let expr_bounds: &[(bool, bool)] = match func {
+                    BinaryFunc::Eq => &[(true, true), (false, true)],
+                    BinaryFunc::Lt => &[(false, false)],
+                    BinaryFunc::Lte => &[(false, true)],
+                    BinaryFunc::Gt => &[(true, false)],
+                    BinaryFunc::Gte => &[(true, true)],
+                    _ => &[],
+                };
+                for (is_lower, inclusive) in expr_bounds {
+                    bounds.push((expr, literal, *is_lower != literal_on_left, *inclusive));
+                }
+            }
+        }
+        bounds
+            .iter()
+            .any(|(expr, lower, is_lower, lower_inclusive)| {
+                *is_lower
+                    && bounds
+                        .iter()
+                        .any(|(other, upper, is_lower, upper_inclusive)| {
+                            if *is_lower
+                                || expr != other
+                                || !lower
+                                    .typ(&[])
+                                    .scalar_type
+                                    .base_eq(&upper.typ(&[]).scalar_type)
+                            {
+                                return false;
+                            }
+                            let lower = lower.as_literal().unwrap().unwrap();
+                            let upper = upper.as_literal().unwrap().unwrap();
+                            lower > upper
+                                || (lower == upper && !(*lower_inclusive && *upper_inclusive))
+                        })
+            })
+    }
+
     /// Determines if `self` is
     /// `<expr> < <literal>` or
     /// `<expr> > <literal>` or
@@ -551,6 +849,54 @@ impl MirScalarExpr {
         }
     }
 
+    /// If `self` is the AND of exactly two comparisons `x >= a` and `x <= b`, in either order and
+    /// with the operands of each comparison in either order, returns `(x, a, b)`, i.e., the
+    /// operands of the equivalent `x BETWEEN a AND b`.
+    ///
+    /// Returns `None` if the comparisons don't share the same `x`, or if either of them is strict,
+    /// as BETWEEN can't represent that.
+    pub fn recognize_between(&self) -> Option<(MirScalarExpr, MirScalarExpr, MirScalarExpr)> {
+        let MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs,
+        } = self
+        else {
+            return None;
+        };
+        let [first, second] = exprs.as_slice() else {
+            return None;
+        };
+        // The ways of reading `e` as `x >= bound` (true) or `x <= bound` (false), as
+        // `(x, bound, is_lower_bound)`.
+        fn bounds(e: &MirScalarExpr) -> Vec<(&MirScalarExpr, &MirScalarExpr, bool)> {
+            match e {
+                MirScalarExpr::CallBinary {
+                    func: BinaryFunc::Gte,
+                    expr1,
+                    expr2,
+                } => vec![(expr1, expr2, true), (expr2, expr1, false)],
+                MirScalarExpr::CallBinary {
+                    func: BinaryFunc::Lte,
+                    expr1,
+                    expr2,
+                } => vec![(expr1, expr2, false), (expr2, expr1, true)],
+                _ => vec![],
+            }
+        }
+        bounds(first)
+            .into_iter()
+            .cartesian_product(bounds(second))
+            .find_map(|((x1, bound1, lower1), (x2, bound2, lower2))| {
+                if x1 != x2 || lower1 == lower2 {
+                    None
+                } else if lower1 {
+                    Some((x1.clone(), bound1.clone(), bound2.clone()))
+                } else {
+                    Some((x1.clone(), bound2.clone(), bound1.clone()))
+                }
+            })
+    }
+
     /// Rewrites column indices with their value in `permutation`.
     ///
     /// This method is applicable even when `permutation` is not a
@@ -579,6 +925,133 @@ impl MirScalarExpr {
         });
     }
 
+    /// Rewrites each column index `i` in `self` to `f(i)`.
+    ///
+    /// `f` is called once per occurrence of a column reference, not once per distinct column,
+    /// so a column referenced twice is passed to `f` twice.
+    pub fn map_columns<F: FnMut(usize) -> usize>(&mut self, mut f: F) {
+        #[allow(deprecated)]
+        self.visit_mut_post_nolimit(&mut |e| {
+            if let MirScalarExpr::Column(i) = e {
+                *i = f(*i);
+            }
+        });
+    }
+
+    /// Calls `f` on every column index in `self`, allowing it to be rewritten in place.
+    ///
+    /// Unlike the visitor-based column rewrites, this walks the expression iteratively with an
+    /// explicit stack, so it neither recurses nor checks the recursion limit, which makes it
+    /// suitable for wide or deep expressions in hot loops.
+    pub fn rewrite_column_indices(&mut self, mut f: impl FnMut(&mut usize)) {
+        use MirScalarExpr::*;
+        let mut todo = vec![self];
+        while let Some(expr) = todo.pop() {
+            match expr {
+                Column(i) => f(i),
+                Literal(_, _) | CallUnmaterializable(_) => (),
+                CallUnary { expr, .. } => todo.push(expr),
+                CallBinary { expr1, expr2, .. } => {
+                    todo.push(expr1);
+                    todo.push(expr2);
+                }
+                CallVariadic { exprs, .. } => todo.extend(exprs.iter_mut()),
+                If { cond, then, els } => {
+                    todo.push(cond);
+                    todo.push(then);
+                    todo.push(els);
+                }
+            }
+        }
+    }
+
+    /// Adds `offset` to every column index in `self`.
+    ///
+    /// This is useful when `self` refers to the columns of a relation that is appended to
+    /// another relation of arity `offset`.
+    pub fn shift_columns(&mut self, offset: usize) {
+        #[allow(deprecated)]
+        self.visit_mut_post_nolimit(&mut |e| {
+            if let MirScalarExpr::Column(i) = e {
+                *i += offset;
+            }
+        });
+    }
+
+    /// Replaces every occurrence of `Column(col)` in `self` with a clone of `with`.
+    ///
+    /// Unlike [`MirScalarExpr::permute`], which only renumbers columns, this inlines an
+    /// arbitrary expression, and no other column is shifted. Columns referenced by `with` are
+    /// interpreted in the same namespace as `self`, and are not themselves replaced.
+    pub fn replace_column(&mut self, col: usize, with: &MirScalarExpr) {
+        #[allow(deprecated)]
+        self.visit_mut_post_nolimit(&mut |e| {
+            if let MirScalarExpr::Column(i) = e {
+                if *i == col {
+                    *e = with.clone();
+                }
+            }
+        });
+    }
+
+    /// Replaces every cast in `self` that has a [try variant](UnaryFunc::try_variant) with that
+    /// variant, so that invalid input evaluates to null instead of an error.
+    ///
+    /// Casts without a try variant, and all other functions, are left unchanged.
+    pub fn soften_casts(&mut self) {
+        #[allow(deprecated)]
+        self.visit_mut_post_nolimit(&mut |e| {
+            if let MirScalarExpr::CallUnary { func, .. } = e {
+                if let Some(try_func) = func.try_variant() {
+                    *func = try_func;
+                }
+            }
+        });
+    }
+
+    /// Replaces every subexpression of `self` that is equal to `from` with `to`, and returns
+    /// the number of replacements.
+    ///
+    /// Replaced subexpressions are not visited again, so `to` may itself contain `from`.
+    pub fn substitute(&mut self, from: &MirScalarExpr, to: &MirScalarExpr) -> usize {
+        let mut count = 0;
+        #[allow(deprecated)]
+        self.visit_mut_pre_post_nolimit(
+            &mut |e| {
+                if e == from {
+                    *e = to.clone();
+                    count += 1;
+                    Some(vec![])
+                } else {
+                    None
+                }
+            },
+            &mut |_| {},
+        );
+        count
+    }
+
+    /// Replaces each literal in `self` with a reference to a new column, numbered from one past
+    /// the largest column that `self` references, and returns the extracted literals in the
+    /// order of their new columns.
+    ///
+    /// This turns `self` into a template that can be instantiated with different values by
+    /// appending them to the input columns.
+    pub fn extract_literals(&mut self) -> Vec<(Result<Row, EvalError>, ColumnType)> {
+        let first_column = self.column_max().map_or(0, |c| c + 1);
+        let mut literals = Vec::new();
+        #[allow(deprecated)]
+        self.visit_mut_post_nolimit(&mut |e| {
+            if e.is_literal() {
+                let column = MirScalarExpr::Column(first_column + literals.len());
+                if let MirScalarExpr::Literal(res, typ) = mem::replace(e, column) {
+                    literals.push((res, typ));
+                }
+            }
+        });
+        literals
+    }
+
     pub fn support(&self) -> BTreeSet<usize> {
         let mut support = BTreeSet::new();
         #[allow(deprecated)]
@@ -590,6 +1063,45 @@ impl MirScalarExpr {
         support
     }
 
+    /// Returns the largest column index referenced by `self`, or `None` if `self` references no
+    /// columns.
+    ///
+    /// Equivalent to `self.support().last()`, but without building the set.
+    pub fn column_max(&self) -> Option<usize> {
+        let mut max = None;
+        #[allow(deprecated)]
+        self.visit_post_nolimit(&mut |e| {
+            if let MirScalarExpr::Column(i) = e {
+                max = max.max(Some(*i));
+            }
+        });
+        max
+    }
+
+    /// Walks `self` in pre-order, calling `f` on each node until it returns `true` to signal
+    /// that it has rewritten that node. The walk then stops, so at most one site is rewritten,
+    /// and the children of the rewritten node are not visited.
+    ///
+    /// Returns whether `f` returned `true` for any node.
+    pub fn rewrite_first<F: FnMut(&mut MirScalarExpr) -> bool>(&mut self, mut f: F) -> bool {
+        fn rewrite_first_inner<F: FnMut(&mut MirScalarExpr) -> bool>(
+            expr: &mut MirScalarExpr,
+            f: &mut F,
+        ) -> bool {
+            if f(expr) {
+                return true;
+            }
+            let mut done = false;
+            expr.visit_mut_children(|child| {
+                if !done {
+                    done = mz_ore::stack::maybe_grow(|| rewrite_first_inner(child, f));
+                }
+            });
+            done
+        }
+        rewrite_first_inner(self, &mut f)
+    }
+
     pub fn take(&mut self) -> Self {
         mem::replace(self, MirScalarExpr::literal_null(ScalarType::String))
     }
@@ -602,6 +1114,14 @@ impl MirScalarExpr {
         }
     }
 
+    /// Like [`MirScalarExpr::as_literal`], but clones the error instead of borrowing it, so
+    /// that callers can propagate it with `?`.
+    ///
+    /// Returns `None` if `self` is not a literal.
+    pub fn into_literal_datum(&self) -> Option<Result<Datum, EvalError>> {
+        self.as_literal().map(|lit| lit.map_err(EvalError::clone))
+    }
+
     pub fn as_literal_owned(&self) -> Option<Result<Row, EvalError>> {
         if let MirScalarExpr::Literal(lit, _column_type) = self {
             Some(lit.clone())
@@ -617,6 +1137,13 @@ impl MirScalarExpr {
         }
     }
 
+    pub fn as_literal_bytes(&self) -> Option<&[u8]> {
+        match self.as_literal() {
+            Some(Ok(Datum::Bytes(b))) => Some(b),
+            _ => None,
+        }
+    }
+
     pub fn as_literal_err(&self) -> Option<&EvalError> {
         self.as_literal().and_then(|lit| lit.err())
     }
@@ -654,6 +1181,26 @@ impl MirScalarExpr {
         }
     }
 
+    /// If self is `NOT(x IS NULL)`, i.e., `x IS NOT NULL`, return `x`, otherwise `None`.
+    ///
+    /// There is no dedicated `IsNotNull` function, and `reduce` keeps this shape intact.
+    pub fn as_is_not_null(&self) -> Option<&MirScalarExpr> {
+        if let MirScalarExpr::CallUnary {
+            func: UnaryFunc::Not(func::Not),
+            expr,
+        } = self
+        {
+            if let MirScalarExpr::CallUnary {
+                func: UnaryFunc::IsNull(func::IsNull),
+                expr,
+            } = &**expr
+            {
+                return Some(expr);
+            }
+        }
+        None
+    }
+
     /// Reduces a complex expression where possible.
     ///
     /// Also canonicalizes the expression.
@@ -699,29 +1246,7 @@ impl MirScalarExpr {
                                     *e = expr
                                 }
                             } else if *func == UnaryFunc::Not(func::Not) {
-                                // Push down not expressions
-                                match &mut **expr {
-                                    // Two negates cancel each other out.
-                                    MirScalarExpr::CallUnary {
-                                        expr: inner_expr,
-                                        func: UnaryFunc::Not(func::Not),
-                                    } => *e = inner_expr.take(),
-                                    // Transforms `NOT(a <op> b)` to `a negate(<op>) b`
-                                    // if a negation exists.
-                                    MirScalarExpr::CallBinary { expr1, expr2, func } => {
-                                        if let Some(negated_func) = func.negate() {
-                                            *e = MirScalarExpr::CallBinary {
-                                                expr1: Box::new(expr1.take()),
-                                                expr2: Box::new(expr2.take()),
-                                                func: negated_func,
-                                            }
-                                        }
-                                    }
-                                    MirScalarExpr::CallVariadic { .. } => {
-                                        e.demorgans();
-                                    }
-                                    _ => {}
-                                }
+                                e.push_down_not();
                             }
                         }
                         _ => {}
@@ -744,6 +1269,15 @@ impl MirScalarExpr {
                             {
                                 *e = exprs.swap_remove(i);
                             }
+                        } else if let Some(reduced) = reduce_timezone_round_trip(func, expr) {
+                            *e = reduced;
+                        } else if let Some(reduced) = reduce_cast(func, expr, column_types) {
+                            *e = reduced;
+                        } else if matches!(func, UnaryFunc::RoundNumeric(_))
+                            && is_integral_numeric(expr)
+                        {
+                            // Rounding an integer does nothing.
+                            *e = expr.take();
                         }
                     }
                     MirScalarExpr::CallBinary { func, expr1, expr2 } => {
@@ -791,6 +1325,18 @@ impl MirScalarExpr {
                                     ),
                                 };
                             }
+                        } else if matches!(*func, BinaryFunc::Encode | BinaryFunc::Decode)
+                            && expr2.is_literal()
+                        {
+                            // We can at least validate the format once, rather than erroring on
+                            // every row.
+                            let format = expr2.as_literal_str().unwrap();
+                            if let Err(err) = func::encoding::lookup_format(format) {
+                                *e = MirScalarExpr::literal(
+                                    Err(err),
+                                    e.typ(column_types).scalar_type,
+                                );
+                            }
                         } else if *func == BinaryFunc::ExtractInterval && expr1.is_literal() {
                             let units = expr1.as_literal_str().unwrap();
                             *e = match units.parse::<DateTimeUnits>() {
@@ -966,6 +1512,37 @@ impl MirScalarExpr {
                                     e.typ(column_types).scalar_type,
                                 ),
                             }
+                        } else if matches!(
+                            func,
+                            BinaryFunc::TimezoneIntervalTimestamp
+                                | BinaryFunc::TimezoneIntervalTimestampTz
+                        ) && expr1.is_literal()
+                        {
+                            // Like the string timezones above, a literal interval that fits into a
+                            // fixed offset is converted once and embedded into the UnaryFunc.
+                            if let Some(Ok(Datum::Interval(interval))) = expr1.as_literal() {
+                                if let Some(tz) = func::timezone_from_interval(interval) {
+                                    *e = match tz {
+                                        Ok(tz) => MirScalarExpr::CallUnary {
+                                            func: if *func == BinaryFunc::TimezoneIntervalTimestamp
+                                            {
+                                                UnaryFunc::TimezoneTimestamp(
+                                                    func::TimezoneTimestamp(tz),
+                                                )
+                                            } else {
+                                                UnaryFunc::TimezoneTimestampTz(
+                                                    func::TimezoneTimestampTz(tz),
+                                                )
+                                            },
+                                            expr: Box::new(expr2.take()),
+                                        },
+                                        Err(err) => MirScalarExpr::literal(
+                                            Err(err),
+                                            e.typ(column_types).scalar_type,
+                                        ),
+                                    }
+                                }
+                            }
                         } else if let BinaryFunc::TimezoneTime { wall_time } = func {
                             if expr1.is_literal() {
                                 let tz = expr1.as_literal_str().unwrap();
@@ -983,6 +1560,65 @@ impl MirScalarExpr {
                                     ),
                                 }
                             }
+                        } else if let Some(reduced) =
+                            reduce_bitwise_identity(func, expr1, expr2, column_types)
+                        {
+                            *e = reduced;
+                        } else if let Some(reduced) =
+                            reduce_arithmetic_identity(func, expr1, expr2, column_types)
+                        {
+                            *e = reduced;
+                        } else if let Some(reduced) =
+                            reduce_abs_comparison(func, expr1, expr2, column_types)
+                        {
+                            *e = reduced;
+                        } else if let Some(reduced) = reduce_array_length(func, expr1, expr2) {
+                            *e = reduced;
+                        } else if *func == BinaryFunc::RoundNumeric
+                            && is_integral_numeric(expr1)
+                            && matches!(expr2.as_literal(), Some(Ok(Datum::Int32(n))) if n >= 0)
+                        {
+                            // Rounding an integer to zero or more decimal places does nothing.
+                            // (Negative places round to tens, hundreds, etc., which does.)
+                            *e = expr1.take();
+                        } else if *func == BinaryFunc::TextConcat
+                            && (expr1.as_literal_str() == Some("")
+                                || expr2.as_literal_str() == Some(""))
+                        {
+                            // x || '' --> x and '' || x --> x, as long as `x` is already `text`.
+                            // A NULL `x` still yields NULL.
+                            let other = if expr1.as_literal_str() == Some("") {
+                                expr2
+                            } else {
+                                expr1
+                            };
+                            if other.typ(column_types).scalar_type == ScalarType::String {
+                                *e = other.take();
+                            }
+                        } else if *func == BinaryFunc::Eq && expr1 == expr2 {
+                            // `x = x` is NULL if `x` is NULL, and true otherwise.
+                            if !expr1.typ(column_types).nullable {
+                                // Only drop `x` if that doesn't also drop an error.
+                                if !expr1.could_error() {
+                                    *e = MirScalarExpr::literal_true();
+                                }
+                            } else {
+                                // NULL OR NOT(x IS NULL) --> NULL if `x` is NULL, true otherwise.
+                                *e = MirScalarExpr::literal_null(ScalarType::Bool)
+                                    .or(expr1.take().call_is_null().not());
+                            }
+                        } else if *func == BinaryFunc::NotEq && expr1 == expr2 {
+                            // `x != x` is NULL if `x` is NULL, and false otherwise.
+                            if !expr1.typ(column_types).nullable {
+                                // Only drop `x` if that doesn't also drop an error.
+                                if !expr1.could_error() {
+                                    *e = MirScalarExpr::literal_false();
+                                }
+                            } else {
+                                // NULL AND (x IS NULL) --> NULL if `x` is NULL, false otherwise.
+                                *e = MirScalarExpr::literal_null(ScalarType::Bool)
+                                    .and(expr1.take().call_is_null());
+                            }
                         } else if matches!(*func, BinaryFunc::Eq | BinaryFunc::NotEq)
                             && expr2 < expr1
                         {
@@ -1116,6 +1752,34 @@ impl MirScalarExpr {
                             } else if exprs.len() == 1 {
                                 // Only one argument, so the coalesce is a no-op.
                                 *e = exprs[0].take();
+                            } else if let Some(i) = exprs.iter().position(|e| {
+                                matches!(e, MirScalarExpr::If { then, els, .. }
+                                    if els.is_literal_null() && !then.typ(column_types).nullable)
+                            }) {
+                                // `coalesce(.., CASE WHEN c THEN t ELSE NULL END, rest..)`, where
+                                // `t` is not nullable, can only take the `ELSE` branch if `c`
+                                // is not true, so it is equivalent to
+                                // `coalesce(.., CASE WHEN c THEN t ELSE coalesce(rest..) END)`.
+                                // The `If` is still evaluated only when all preceding arguments
+                                // are null, and the literal null goes away. If the `If` is
+                                // already the last argument, there is nothing to pull in.
+                                if i + 1 < exprs.len() {
+                                    let rest = exprs.split_off(i + 1);
+                                    let rest = if rest.len() == 1 {
+                                        rest.into_first()
+                                    } else {
+                                        MirScalarExpr::CallVariadic {
+                                            func: VariadicFunc::Coalesce,
+                                            exprs: rest,
+                                        }
+                                    };
+                                    if let MirScalarExpr::If { els, .. } = &mut exprs[i] {
+                                        **els = rest;
+                                    }
+                                    if exprs.len() == 1 {
+                                        *e = exprs[0].take();
+                                    }
+                                }
                             }
                         } else if exprs.iter().all(|e| e.is_literal()) {
                             *e = eval(e);
@@ -1153,10 +1817,23 @@ impl MirScalarExpr {
                             let ind_exprs = exprs.split_off(1);
                             let top_list_create = exprs.swap_remove(0);
                             *e = reduce_list_create_list_index_literal(top_list_create, ind_exprs);
+                        } else if let Some(literal) = dominating_greatest_least_literal(func, exprs)
+                        {
+                            *e = literal;
+                        } else if *func == VariadicFunc::PadLeading
+                            && exprs[1].as_literal() == Some(Ok(Datum::Int64(0)))
+                            && exprs
+                                .iter()
+                                .all(|e| !e.typ(column_types).nullable && !e.could_error())
+                        {
+                            // lpad(x, 0[, pad]) --> '', unless a NULL or an error in `x` or `pad`
+                            // would take precedence.
+                            *e = MirScalarExpr::literal_ok(Datum::String(""), ScalarType::String);
                         } else if *func == VariadicFunc::Or || *func == VariadicFunc::And {
                             // Note: It's important that we have called `flatten_associative` above.
-                            e.undistribute_and_or();
-                            e.reduce_and_canonicalize_and_or();
+                            e.simplify_and_or();
+                            e.reduce_implied_comparisons();
+                            e.reduce_range_predicates(column_types);
                         }
                     }
                     MirScalarExpr::If { cond, then, els } => {
@@ -1176,6 +1853,58 @@ impl MirScalarExpr {
                             }
                         } else if then == els {
                             *e = then.take();
+                        } else if matches!(
+                            &**then,
+                            MirScalarExpr::If { cond: inner, .. } if inner == cond
+                        ) {
+                            // CASE WHEN c THEN (CASE WHEN c THEN a ELSE b END) ELSE d END
+                            // --> CASE WHEN c THEN a ELSE d END
+                            // The inner `c` is only evaluated when the outer `c` is true.
+                            if let MirScalarExpr::If { then: inner, .. } = &mut **then {
+                                let inner = inner.take();
+                                **then = inner;
+                            }
+                        } else if matches!(
+                            &**els,
+                            MirScalarExpr::If { cond: inner, .. } if inner == cond
+                        ) {
+                            // CASE WHEN c THEN a ELSE (CASE WHEN c THEN b ELSE d END) END
+                            // --> CASE WHEN c THEN a ELSE d END
+                            // The inner `c` is only evaluated when the outer `c` is false or NULL,
+                            // in which case the inner `CASE` takes its `ELSE` branch, too.
+                            if let MirScalarExpr::If { els: inner, .. } = &mut **els {
+                                let inner = inner.take();
+                                **els = inner;
+                            }
+                        } else if let (
+                            MirScalarExpr::CallUnary {
+                                func: then_func,
+                                expr: then_expr,
+                            },
+                            MirScalarExpr::CallUnary {
+                                func: els_func,
+                                expr: els_expr,
+                            },
+                        ) = (&mut **then, &mut **els)
+                        {
+                            // CASE WHEN c THEN f(a) ELSE f(b) END --> f(CASE WHEN c THEN a ELSE b END)
+                            // `f` is evaluated exactly once either way, so this is only
+                            // unsafe if `f` could error on one of `a` or `b` but not the
+                            // other, which we rule out by requiring that it can't error.
+                            // Polymorphic functions, e.g., `IS NULL`, accept inputs of different
+                            // types, which can't be combined into a single `CASE`.
+                            if then_func == els_func
+                                && !then_func.could_error()
+                                && then_expr
+                                    .typ(column_types)
+                                    .scalar_type
+                                    .base_eq(&els_expr.typ(column_types).scalar_type)
+                            {
+                                *e = cond
+                                    .take()
+                                    .if_then_else(then_expr.take(), els_expr.take())
+                                    .call_unary(then_func.clone());
+                            }
                         } else if then.is_literal_ok() && els.is_literal_ok() {
                             match (then.as_literal(), els.as_literal()) {
                                 // Note: NULLs from the condition should not be propagated to the result
@@ -1344,19 +2073,843 @@ impl MirScalarExpr {
         }
 
         /* #endregion */
-    }
 
-    /// Decompose an IsNull expression into a disjunction of
-    /// simpler expressions.
-    ///
-    /// Assumes that `self` is the expression inside of an IsNull.
-    /// Returns `Some(expressions)` if the outer IsNull is to be
-    /// replaced by some other expression. Note: if it returns
-    /// None, it might still have mutated *self.
-    fn decompose_is_null(&mut self) -> Option<MirScalarExpr> {
-        // TODO: allow simplification of unmaterializable functions
+        /// The smallest and largest values of `typ`, if it has a bounded domain.
+        fn scalar_type_bounds(typ: &ScalarType) -> Option<(Datum<'static>, Datum<'static>)> {
+            match typ {
+                ScalarType::Int16 => Some((Datum::Int16(i16::MIN), Datum::Int16(i16::MAX))),
+                ScalarType::Int32 => Some((Datum::Int32(i32::MIN), Datum::Int32(i32::MAX))),
+                ScalarType::Int64 => Some((Datum::Int64(i64::MIN), Datum::Int64(i64::MAX))),
+                ScalarType::UInt16 => Some((Datum::UInt16(u16::MIN), Datum::UInt16(u16::MAX))),
+                ScalarType::UInt32 => Some((Datum::UInt32(u32::MIN), Datum::UInt32(u32::MAX))),
+                ScalarType::UInt64 => Some((Datum::UInt64(u64::MIN), Datum::UInt64(u64::MAX))),
+                _ => None,
+            }
+        }
 
-        match self {
+        /// If `func` is `greatest` (`least`) and one of `exprs` is a literal at the top (bottom)
+        /// of its type's domain, then the result is always that literal, and it is returned.
+        ///
+        /// This doesn't apply if any of the other arguments could error.
+        fn dominating_greatest_least_literal(
+            func: &VariadicFunc,
+            exprs: &[MirScalarExpr],
+        ) -> Option<MirScalarExpr> {
+            if !matches!(func, VariadicFunc::Greatest | VariadicFunc::Least)
+                || exprs.iter().any(|e| e.could_error())
+            {
+                return None;
+            }
+            exprs
+                .iter()
+                .find(|e| match e {
+                    MirScalarExpr::Literal(Ok(row), typ) => {
+                        match scalar_type_bounds(&typ.scalar_type) {
+                            Some((min, max)) => {
+                                let datum = row.unpack_first();
+                                match func {
+                                    VariadicFunc::Greatest => datum == max,
+                                    _ => datum == min,
+                                }
+                            }
+                            None => false,
+                        }
+                    }
+                    _ => false,
+                })
+                .cloned()
+        }
+
+        /// Simplifies a bitwise AND/OR/XOR where one operand is a literal that is either the
+        /// identity of the operation (`x & -1`, `x | 0`, `x ^ 0` --> `x`) or absorbs the other
+        /// operand (`x & 0` --> `0`, `x | -1` --> `-1`).
+        ///
+        /// Absorption only happens when the other operand can be neither NULL nor an error, as
+        /// those would otherwise be dropped.
+        fn reduce_bitwise_identity(
+            func: &BinaryFunc,
+            expr1: &mut MirScalarExpr,
+            expr2: &mut MirScalarExpr,
+            column_types: &[ColumnType],
+        ) -> Option<MirScalarExpr> {
+            use BinaryFunc::*;
+
+            let is_zero = |d: &Datum| {
+                matches!(
+                    d,
+                    Datum::Int16(0)
+                        | Datum::Int32(0)
+                        | Datum::Int64(0)
+                        | Datum::UInt16(0)
+                        | Datum::UInt32(0)
+                        | Datum::UInt64(0)
+                )
+            };
+            let is_all_ones = |d: &Datum| {
+                matches!(
+                    d,
+                    Datum::Int16(-1)
+                        | Datum::Int32(-1)
+                        | Datum::Int64(-1)
+                        | Datum::UInt16(u16::MAX)
+                        | Datum::UInt32(u32::MAX)
+                        | Datum::UInt64(u64::MAX)
+                )
+            };
+            // All three operations are commutative, so look for the literal on either side.
+            let (literal, other) = if expr1.is_literal_ok() {
+                (expr1, expr2)
+            } else if expr2.is_literal_ok() {
+                (expr2, expr1)
+            } else {
+                return None;
+            };
+            let datum = literal.as_literal().unwrap().unwrap();
+            let (is_identity, is_absorbing) = match func {
+                BitAndInt16 | BitAndInt32 | BitAndInt64 | BitAndUInt16 | BitAndUInt32
+                | BitAndUInt64 => (is_all_ones(&datum), is_zero(&datum)),
+                BitOrInt16 | BitOrInt32 | BitOrInt64 | BitOrUInt16 | BitOrUInt32 | BitOrUInt64 => {
+                    (is_zero(&datum), is_all_ones(&datum))
+                }
+                BitXorInt16 | BitXorInt32 | BitXorInt64 | BitXorUInt16 | BitXorUInt32
+                | BitXorUInt64 => (is_zero(&datum), false),
+                _ => return None,
+            };
+            if is_identity {
+                Some(other.take())
+            } else if is_absorbing && !other.typ(column_types).nullable && !other.could_error() {
+                Some(literal.take())
+            } else {
+                None
+            }
+        }
+
+        /// Simplifies integer addition, subtraction, and multiplication where one operand is a
+        /// literal identity (`x + 0`, `x - 0`, `x * 1` --> `x`) or absorbs the other operand
+        /// (`x * 0` --> `0`).
+        ///
+        /// Absorption only happens when the other operand can be neither NULL nor an error, as
+        /// those would otherwise be dropped. Floats are excluded because `NaN * 0` and
+        /// `inf * 0` are not `0`, and `numeric` because of its signed zero.
+        fn reduce_arithmetic_identity(
+            func: &BinaryFunc,
+            expr1: &mut MirScalarExpr,
+            expr2: &mut MirScalarExpr,
+            column_types: &[ColumnType],
+        ) -> Option<MirScalarExpr> {
+            use BinaryFunc::*;
+
+            let is_zero = |e: &MirScalarExpr| {
+                matches!(
+                    e.as_literal(),
+                    Some(Ok(Datum::Int16(0)
+                        | Datum::Int32(0)
+                        | Datum::Int64(0)
+                        | Datum::UInt16(0)
+                        | Datum::UInt32(0)
+                        | Datum::UInt64(0)))
+                )
+            };
+            let is_one = |e: &MirScalarExpr| {
+                matches!(
+                    e.as_literal(),
+                    Some(Ok(Datum::Int16(1)
+                        | Datum::Int32(1)
+                        | Datum::Int64(1)
+                        | Datum::UInt16(1)
+                        | Datum::UInt32(1)
+                        | Datum::UInt64(1)))
+                )
+            };
+            let absorbable = |e: &MirScalarExpr| !e.typ(column_types).nullable && !e.could_error();
+            match func {
+                AddInt16 | AddInt32 | AddInt64 | AddUInt16 | AddUInt32 | AddUInt64 => {
+                    if is_zero(expr2) {
+                        Some(expr1.take())
+                    } else if is_zero(expr1) {
+                        Some(expr2.take())
+                    } else {
+                        None
+                    }
+                }
+                SubInt16 | SubInt32 | SubInt64 | SubUInt16 | SubUInt32 | SubUInt64
+                    if is_zero(expr2) =>
+                {
+                    Some(expr1.take())
+                }
+                MulInt16 | MulInt32 | MulInt64 | MulUInt16 | MulUInt32 | MulUInt64 => {
+                    if is_one(expr2) {
+                        Some(expr1.take())
+                    } else if is_one(expr1) {
+                        Some(expr2.take())
+                    } else if is_zero(expr2) && absorbable(expr1) {
+                        Some(expr2.take())
+                    } else if is_zero(expr1) && absorbable(expr2) {
+                        Some(expr1.take())
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        }
+
+        /// Folds `array_length(ARRAY[e1, ..., en], 1)` to `n`, as the first dimension of a
+        /// one-dimensional array is its element count, whatever the elements are.
+        ///
+        /// Arrays of arrays are left alone: their dimensions depend on the values of the inner
+        /// arrays, which may be empty or mismatched. So are empty arrays, which have no
+        /// dimensions at all.
+        fn reduce_array_length(
+            func: &BinaryFunc,
+            expr1: &MirScalarExpr,
+            expr2: &MirScalarExpr,
+        ) -> Option<MirScalarExpr> {
+            match (func, expr1, expr2.as_literal()) {
+                (
+                    BinaryFunc::ArrayLength,
+                    MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::ArrayCreate { elem_type },
+                        exprs,
+                    },
+                    Some(Ok(Datum::Int64(1))),
+                ) if !matches!(elem_type, ScalarType::Array(_))
+                    && !exprs.is_empty()
+                    && !exprs.iter().any(|e| e.could_error()) =>
+                {
+                    let len = i32::try_from(exprs.len()).ok()?;
+                    Some(MirScalarExpr::literal_ok(
+                        Datum::Int32(len),
+                        ScalarType::Int32,
+                    ))
+                }
+                _ => None,
+            }
+        }
+
+        /// Reports whether `expr` is an integer cast to an unconstrained `numeric`, which
+        /// `round` leaves unchanged.
+        ///
+        /// `round(x, 0)` is not rewritten to `round(x)` for other `x`, as the two differ in
+        /// the sign of a zero result, e.g., for `round(-0.4)`.
+        fn is_integral_numeric(expr: &MirScalarExpr) -> bool {
+            matches!(
+                expr,
+                MirScalarExpr::CallUnary {
+                    func: UnaryFunc::CastInt16ToNumeric(func::CastInt16ToNumeric(None))
+                        | UnaryFunc::CastInt32ToNumeric(func::CastInt32ToNumeric(None))
+                        | UnaryFunc::CastInt64ToNumeric(func::CastInt64ToNumeric(None))
+                        | UnaryFunc::CastUint16ToNumeric(func::CastUint16ToNumeric(None))
+                        | UnaryFunc::CastUint32ToNumeric(func::CastUint32ToNumeric(None))
+                        | UnaryFunc::CastUint64ToNumeric(func::CastUint64ToNumeric(None)),
+                    ..
+                }
+            )
+        }
+
+        /// Simplifies a round trip between `timestamp` and `timestamptz` through the same time
+        /// zone, i.e., `timezone(tz, timezone(tz, x))` in either direction, to `x`.
+        ///
+        /// This is only done for a zero fixed offset (e.g., `'+00:00'` or `'Z'`), where neither
+        /// conversion changes the date and time. Any other fixed offset is inverted exactly by the
+        /// opposite conversion, but the inner conversion can shift a value near the end of the
+        /// supported range out of range, and folding would drop that error. Named zones are not
+        /// invertible at all: local times skipped by a DST transition are moved forward, and
+        /// repeated ones resolve to the later instant.
+        fn reduce_timezone_round_trip(
+            func: &UnaryFunc,
+            expr: &mut MirScalarExpr,
+        ) -> Option<MirScalarExpr> {
+            let is_zero_offset = |tz: &Timezone| match tz {
+                Timezone::FixedOffset(offset) => offset.local_minus_utc() == 0,
+                Timezone::Tz(_) => false,
+            };
+            match (func, expr) {
+                (
+                    UnaryFunc::TimezoneTimestamp(func::TimezoneTimestamp(outer_tz)),
+                    MirScalarExpr::CallUnary {
+                        func: UnaryFunc::TimezoneTimestampTz(func::TimezoneTimestampTz(inner_tz)),
+                        expr: x,
+                    },
+                )
+                | (
+                    UnaryFunc::TimezoneTimestampTz(func::TimezoneTimestampTz(outer_tz)),
+                    MirScalarExpr::CallUnary {
+                        func: UnaryFunc::TimezoneTimestamp(func::TimezoneTimestamp(inner_tz)),
+                        expr: x,
+                    },
+                ) if outer_tz == inner_tz && is_zero_offset(outer_tz) => Some(x.take()),
+                _ => None,
+            }
+        }
+
+        /// Removes a cast (or other invertible function) that has no effect:
+        ///
+        /// - `f(g(x))` becomes `x` if `g` is a lossless widening cast and `f` its inverse, e.g.,
+        ///   `x::int4::int8::int4`. Narrowing `g`s are left alone, as folding would drop their
+        ///   errors, e.g., in `x::int4::int2::int4`, and so are other inverse pairs, which can
+        ///   change the type or the result, e.g., `x::text::varchar(3)` for a `varchar(10)` `x`.
+        /// - A cast that only changes the type of its input becomes a no-op if the input already
+        ///   has the target type, e.g., a cast to `varchar(5)` of an input that is already a
+        ///   `varchar(5)`.
+        fn reduce_cast(
+            func: &UnaryFunc,
+            expr: &mut MirScalarExpr,
+            column_types: &[ColumnType],
+        ) -> Option<MirScalarExpr> {
+            if matches!(
+                func,
+                UnaryFunc::CastVarCharToString(_) | UnaryFunc::CastStringToVarChar(_)
+            ) {
+                let input_type = expr.typ(column_types);
+                if func.output_type(input_type.clone()).scalar_type == input_type.scalar_type {
+                    return Some(expr.take());
+                }
+            }
+            match expr {
+                MirScalarExpr::CallUnary {
+                    func: inner_func,
+                    expr: x,
+                } if inner_func.is_widening_int_cast()
+                    && func.inverse().as_ref() == Some(inner_func) =>
+                {
+                    let input_type = x.typ(column_types);
+                    let output_type = func.output_type(inner_func.output_type(input_type.clone()));
+                    if output_type.scalar_type == input_type.scalar_type {
+                        Some(x.take())
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        }
+
+        /// Simplifies `abs(x) >= 0` (or `0 <= abs(x)`), which holds for every non-NULL `x`, to
+        /// `true` if `x` is not nullable, and to `NULL OR NOT (x IS NULL)` otherwise.
+        ///
+        /// Only the float and numeric variants of `abs` are recognized: these can't error (NaN
+        /// sorts above all other values), while the integer ones overflow on the minimum value.
+        fn reduce_abs_comparison(
+            func: &BinaryFunc,
+            expr1: &mut MirScalarExpr,
+            expr2: &mut MirScalarExpr,
+            column_types: &[ColumnType],
+        ) -> Option<MirScalarExpr> {
+            let (abs, literal) = match func {
+                BinaryFunc::Gte => (expr1, expr2),
+                BinaryFunc::Lte => (expr2, expr1),
+                _ => return None,
+            };
+            let is_zero = match literal.as_literal() {
+                Some(Ok(Datum::Float32(f))) => f.into_inner() == 0.0,
+                Some(Ok(Datum::Float64(f))) => f.into_inner() == 0.0,
+                Some(Ok(Datum::Numeric(n))) => n.0.is_zero(),
+                _ => false,
+            };
+            match abs {
+                MirScalarExpr::CallUnary {
+                    func:
+                        UnaryFunc::AbsFloat32(_) | UnaryFunc::AbsFloat64(_) | UnaryFunc::AbsNumeric(_),
+                    expr: x,
+                } if is_zero && !x.could_error() => {
+                    if !x.typ(column_types).nullable {
+                        Some(MirScalarExpr::literal_true())
+                    } else {
+                        Some(
+                            MirScalarExpr::literal_null(ScalarType::Bool)
+                                .or(x.take().call_is_null().not()),
+                        )
+                    }
+                }
+                _ => None,
+            }
+        }
+    }
+
+    /// Applies only the boolean simplifications of [`MirScalarExpr::reduce`]: double negation,
+    /// negation of comparisons, De Morgan's laws, flattening, deduplication, identity and
+    /// short-circuiting literals, and (un)distribution/absorption of AND/OR. Arithmetic, casts,
+    /// and other function calls are left untouched.
+    ///
+    /// In addition, `x AND NOT x` is simplified to `false` and `x OR NOT x` to `true` when `x`
+    /// can be neither NULL nor an error, as these laws don't hold in three-valued logic.
+    pub fn simplify_boolean(&mut self, column_types: &[ColumnType]) {
+        let mut old_self = MirScalarExpr::column(0);
+        while old_self != *self {
+            old_self = self.clone();
+            #[allow(deprecated)]
+            self.visit_mut_pre_post_nolimit(
+                &mut |e| {
+                    if let MirScalarExpr::CallUnary {
+                        func: UnaryFunc::Not(func::Not),
+                        ..
+                    } = e
+                    {
+                        e.push_down_not();
+                    }
+                    None
+                },
+                &mut |e| {
+                    if let MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::And | VariadicFunc::Or,
+                        ..
+                    } = e
+                    {
+                        e.flatten_associative();
+                        e.simplify_and_or();
+                        e.reduce_complements(column_types);
+                    }
+                },
+            );
+        }
+    }
+
+    /// Converts `self` into conjunctive normal form, i.e., an AND of ORs of atomic predicates, by
+    /// pushing NOTs inwards with De Morgan's laws and then distributing OR over AND.
+    ///
+    /// The conversion can blow up the expression exponentially, so `self` is left unchanged if
+    /// the [`size`](MirScalarExpr::size) of the result, i.e., its number of nodes, would exceed
+    /// `max_size`. [`MirScalarExpr::CNF_SIZE_LIMIT`] is a reasonable default.
+    pub fn to_cnf(&mut self, max_size: usize) {
+        if let Some(cnf) = self.normal_form(VariadicFunc::And, VariadicFunc::Or, max_size) {
+            *self = cnf;
+        }
+    }
+
+    /// A reasonable default for the maximum number of nodes in the result of
+    /// [`MirScalarExpr::to_cnf`] and [`MirScalarExpr::to_dnf`].
+    pub const CNF_SIZE_LIMIT: usize = 1000;
+
+    /// Converts `self` into disjunctive normal form, i.e., an OR of ANDs of atomic predicates, by
+    /// pushing NOTs inwards with De Morgan's laws and then distributing AND over OR.
+    ///
+    /// Like [`MirScalarExpr::to_cnf`], `self` is left unchanged if the
+    /// [`size`](MirScalarExpr::size) of the result would exceed `max_size` nodes.
+    pub fn to_dnf(&mut self, max_size: usize) {
+        if let Some(dnf) = self.normal_form(VariadicFunc::Or, VariadicFunc::And, max_size) {
+            *self = dnf;
+        }
+    }
+
+    /// Returns `self` as an `outer` of `inner`s of atomic predicates, where `outer` and `inner`
+    /// are AND and OR in either order, or `None` if that would have more than `max_size` nodes.
+    fn normal_form(
+        &self,
+        outer: VariadicFunc,
+        inner: VariadicFunc,
+        max_size: usize,
+    ) -> Option<MirScalarExpr> {
+        let mut expr = self.clone();
+        #[allow(deprecated)]
+        expr.visit_mut_pre_post_nolimit(
+            &mut |e| {
+                // NOT(NOT(x)) --> x
+                while let MirScalarExpr::CallUnary {
+                    func: UnaryFunc::Not(func::Not),
+                    expr,
+                } = e
+                {
+                    match &mut **expr {
+                        MirScalarExpr::CallUnary {
+                            func: UnaryFunc::Not(func::Not),
+                            expr: inner,
+                        } => *e = inner.take(),
+                        _ => break,
+                    }
+                }
+                e.demorgans();
+                None
+            },
+            &mut |e| e.flatten_associative(),
+        );
+        let clauses = expr.normal_form_clauses(&outer, max_size)?;
+        let mut operands = clauses
+            .into_iter()
+            .map(|mut clause| {
+                if clause.len() == 1 {
+                    clause.swap_remove(0)
+                } else {
+                    MirScalarExpr::CallVariadic {
+                        func: inner.clone(),
+                        exprs: clause,
+                    }
+                }
+            })
+            .collect_vec();
+        let result = if operands.len() == 1 {
+            operands.swap_remove(0)
+        } else {
+            MirScalarExpr::CallVariadic {
+                func: outer,
+                exprs: operands,
+            }
+        };
+        if result.size().map_or(false, |size| size <= max_size) {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the clauses of the normal form of `self` whose outer function is `outer`, each
+    /// clause being a list of atoms combined with the other one of AND and OR, or `None` if they
+    /// would contain more than `max_size` atoms. Every atom is at least one node, so this bails
+    /// out early on normal forms that are too large for [`MirScalarExpr::normal_form`].
+    fn normal_form_clauses(
+        &self,
+        outer: &VariadicFunc,
+        max_size: usize,
+    ) -> Option<Vec<Vec<MirScalarExpr>>> {
+        let size = |clauses: &Vec<Vec<MirScalarExpr>>| clauses.iter().map(Vec::len).sum::<usize>();
+        let clauses = match self {
+            MirScalarExpr::CallVariadic { func, exprs } if func == outer => {
+                let mut clauses = Vec::new();
+                for expr in exprs {
+                    clauses.extend(expr.normal_form_clauses(outer, max_size)?);
+                }
+                clauses
+            }
+            MirScalarExpr::CallVariadic {
+                func: VariadicFunc::And | VariadicFunc::Or,
+                exprs,
+            } => {
+                // For CNF, (a AND b) OR (c AND d) -->
+                //     (a OR c) AND (a OR d) AND (b OR c) AND (b OR d)
+                let mut clauses = vec![vec![]];
+                for expr in exprs {
+                    let expr_clauses = expr.normal_form_clauses(outer, max_size)?;
+                    // Saturates, as `max_size` may be `usize::MAX` to mean "no limit".
+                    if clauses.len().saturating_mul(expr_clauses.len()) > max_size {
+                        return None;
+                    }
+                    clauses = clauses
+                        .iter()
+                        .cartesian_product(expr_clauses.iter())
+                        .map(|(clause, expr_clause)| {
+                            clause.iter().chain(expr_clause).cloned().collect()
+                        })
+                        .collect();
+                    if size(&clauses) > max_size {
+                        return None;
+                    }
+                }
+                clauses
+            }
+            _ => vec![vec![self.clone()]],
+        };
+        if size(&clauses) > max_size {
+            return None;
+        }
+        Some(clauses)
+    }
+
+    /// Returns a canonicalized copy of `self` that is suitable for equality checks and hashing:
+    /// chains of associative functions are flattened, the operands of AND, OR, `=`, and `<>` are
+    /// put in a canonical order, and duplicate AND/OR operands are removed.
+    ///
+    /// Unlike [`MirScalarExpr::reduce`], this performs no constant folding, so it is cheap and
+    /// does not depend on column types.
+    pub fn canonical_form(&self) -> MirScalarExpr {
+        let mut expr = self.clone();
+        #[allow(deprecated)]
+        expr.visit_mut_post_nolimit(&mut |e| {
+            e.flatten_associative();
+            match e {
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::And | VariadicFunc::Or,
+                    exprs,
+                } => {
+                    exprs.sort();
+                    exprs.dedup();
+                    if exprs.len() == 1 {
+                        *e = exprs.swap_remove(0);
+                    }
+                }
+                MirScalarExpr::CallBinary {
+                    func: BinaryFunc::Eq | BinaryFunc::NotEq,
+                    expr1,
+                    expr2,
+                } => {
+                    if expr2 < expr1 {
+                        mem::swap(expr1, expr2);
+                    }
+                }
+                _ => {}
+            }
+        });
+        expr
+    }
+
+    /// Pushes a NOT down into its argument where possible.
+    ///
+    /// Assumes that `self` is a call to NOT.
+    fn push_down_not(&mut self) {
+        if let MirScalarExpr::CallUnary { expr, .. } = self {
+            match &mut **expr {
+                // Two negates cancel each other out.
+                MirScalarExpr::CallUnary {
+                    expr: inner_expr,
+                    func: UnaryFunc::Not(func::Not),
+                } => *self = inner_expr.take(),
+                // Transforms `NOT(a <op> b)` to `a negate(<op>) b`
+                // if a negation exists.
+                MirScalarExpr::CallBinary { expr1, expr2, func } => {
+                    if let Some(negated_func) = func.negate() {
+                        *self = MirScalarExpr::CallBinary {
+                            expr1: Box::new(expr1.take()),
+                            expr2: Box::new(expr2.take()),
+                            func: negated_func,
+                        }
+                    }
+                }
+                MirScalarExpr::CallVariadic { .. } => {
+                    self.demorgans();
+                }
+                // `NOT(x IS NULL)` is the canonical form of `x IS NOT NULL`, see
+                // `as_is_not_null`, so it is left alone.
+                _ => {}
+            }
+        }
+    }
+
+    /// Simplifies a flattened AND/OR.
+    fn simplify_and_or(&mut self) {
+        self.undistribute_and_or();
+        self.reduce_and_canonicalize_and_or();
+    }
+
+    /// Simplifies a conjunction that binds a column to a literal with `<column> = <literal>`:
+    /// other comparisons of that column with a literal are dropped if the binding implies them,
+    /// and the whole conjunction becomes `false` if the binding contradicts one of them.
+    ///
+    /// For example, `#0 = 5 AND #0 > 3` --> `#0 = 5`, and `#0 = 5 AND #0 < 3` --> `false`.
+    fn reduce_implied_comparisons(&mut self) {
+        if let MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs,
+        } = self
+        {
+            // (position of the binding conjunct, bound column, literal)
+            //
+            // Only the first binding of each column is used, as a binding must not be dropped
+            // because of a conjunct that it implies in turn. E.g., for a float `#0`, each of
+            // `#0 = 0.0` and `#0 = -0.0` implies the other, but they can't both be dropped.
+            let bindings = exprs
+                .iter()
+                .enumerate()
+                .filter_map(|(i, e)| match e {
+                    MirScalarExpr::CallBinary {
+                        func: BinaryFunc::Eq,
+                        expr1,
+                        expr2,
+                    } if expr2.is_literal_ok() && !expr2.is_literal_null() => {
+                        expr1.as_column().map(|c| (i, c, (**expr2).clone()))
+                    }
+                    _ => None,
+                })
+                .unique_by(|(_, c, _)| *c)
+                .collect_vec();
+            if bindings.is_empty() {
+                return;
+            }
+
+            // Evaluates a comparison between a bound column and a literal, if `conjunct` is one.
+            let temp_storage = RowArena::new();
+            let implied_value = |i: usize, conjunct: &MirScalarExpr| -> Option<bool> {
+                let (func, expr1, expr2) = match conjunct {
+                    MirScalarExpr::CallBinary { func, expr1, expr2 }
+                        if matches!(
+                            func,
+                            BinaryFunc::Eq
+                                | BinaryFunc::NotEq
+                                | BinaryFunc::Lt
+                                | BinaryFunc::Lte
+                                | BinaryFunc::Gt
+                                | BinaryFunc::Gte
+                        ) =>
+                    {
+                        (func, expr1, expr2)
+                    }
+                    _ => return None,
+                };
+                bindings
+                    .iter()
+                    .filter(|(j, _, _)| *j != i)
+                    .find_map(|(_, c, literal)| {
+                        let (expr1, expr2) = match (expr1.as_column(), expr2.as_column()) {
+                            (Some(c1), None) if c1 == *c && expr2.is_literal_ok() => {
+                                (literal.clone(), (**expr2).clone())
+                            }
+                            (None, Some(c2)) if c2 == *c && expr1.is_literal_ok() => {
+                                ((**expr1).clone(), literal.clone())
+                            }
+                            _ => return None,
+                        };
+                        match expr1
+                            .call_binary(expr2, func.clone())
+                            .eval(&[], &temp_storage)
+                        {
+                            Ok(Datum::True) => Some(true),
+                            Ok(Datum::False) => Some(false),
+                            _ => None,
+                        }
+                    })
+            };
+
+            let values = exprs
+                .iter()
+                .enumerate()
+                .map(|(i, e)| implied_value(i, e))
+                .collect_vec();
+            if values.contains(&Some(false)) {
+                *self = MirScalarExpr::literal_false();
+            } else {
+                let mut values = values.into_iter();
+                exprs.retain(|_| values.next().unwrap() != Some(true));
+            }
+        }
+    }
+
+    /// Simplifies a conjunction that bounds an expression from the same side more than once,
+    /// by keeping only the tightest of these bounds. The conjunction becomes `false` if the
+    /// remaining bounds leave no values at all.
+    ///
+    /// For example, `#0 > 5 AND #0 >= 3` --> `#0 > 5`, and `#0 > 5 AND #0 <= 5` --> `false`.
+    /// Only comparisons with non-NULL literals are considered.
+    fn reduce_range_predicates(&mut self, column_types: &[ColumnType]) {
+        let MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs,
+        } = self
+        else {
+            return;
+        };
+
+        // The positions of the conjuncts to drop, and the expressions with an empty range.
+        let mut redundant = BTreeSet::new();
+        let mut empty = Vec::new();
+        {
+            // bounded expression -> [(position, literal, is lower bound, is strict)]
+            let mut bounds: BTreeMap<&MirScalarExpr, Vec<_>> = BTreeMap::new();
+            for (i, conjunct) in exprs.iter().enumerate() {
+                let MirScalarExpr::CallBinary { func, expr1, expr2 } = conjunct else {
+                    continue;
+                };
+                if !conjunct.any_expr_ineq_literal() {
+                    continue;
+                }
+                // Normalize to `<expr> <func> <literal>`.
+                let (expr, literal, is_lower) = if expr2.is_literal() {
+                    (
+                        expr1,
+                        expr2,
+                        matches!(func, BinaryFunc::Gt | BinaryFunc::Gte),
+                    )
+                } else {
+                    (
+                        expr2,
+                        expr1,
+                        matches!(func, BinaryFunc::Lt | BinaryFunc::Lte),
+                    )
+                };
+                let is_strict = matches!(func, BinaryFunc::Lt | BinaryFunc::Gt);
+                match literal.as_literal() {
+                    Some(Ok(datum)) if !expr.is_literal() && !datum.is_null() => bounds
+                        .entry(&**expr)
+                        .or_default()
+                        .push((i, datum, is_lower, is_strict)),
+                    _ => {}
+                }
+            }
+
+            for (expr, bounds) in bounds {
+                // Among the lower (resp. upper) bounds, the tightest one has the greatest
+                // (resp. least) literal, and is strict if there is a tie.
+                let tightest = |is_lower: bool| {
+                    bounds
+                        .iter()
+                        .filter(|(_, _, lower, _)| *lower == is_lower)
+                        .max_by(|(_, d1, _, s1), (_, d2, _, s2)| {
+                            let by_literal = if is_lower { d1.cmp(d2) } else { d2.cmp(d1) };
+                            by_literal.then(s1.cmp(s2))
+                        })
+                };
+                let lower = tightest(true);
+                let upper = tightest(false);
+                for (i, _, is_lower, _) in &bounds {
+                    let kept = if *is_lower { lower } else { upper };
+                    if kept.map(|(k, ..)| k) != Some(i) {
+                        redundant.insert(*i);
+                    }
+                }
+                if let (Some((_, lo, _, lo_strict)), Some((_, hi, _, hi_strict))) = (lower, upper) {
+                    if lo > hi || (lo == hi && (*lo_strict || *hi_strict)) {
+                        empty.push(expr.clone());
+                        redundant.extend(bounds.iter().map(|(i, ..)| *i));
+                    }
+                }
+            }
+        }
+        if redundant.is_empty() {
+            return;
+        }
+
+        if empty
+            .iter()
+            .any(|e| !e.typ(column_types).nullable && !e.could_error())
+        {
+            *self = MirScalarExpr::literal_false();
+            return;
+        }
+        let mut position = 0;
+        exprs.retain(|_| {
+            position += 1;
+            !redundant.contains(&(position - 1))
+        });
+        if !empty.is_empty() {
+            // The bounds on a NULL expression evaluate to NULL rather than false. We keep the
+            // expressions around to preserve that, as well as any errors they might produce.
+            exprs.push(MirScalarExpr::literal_null(ScalarType::Bool));
+            exprs.extend(empty.into_iter().map(|e| e.call_is_null()));
+        }
+        self.reduce_and_canonicalize_and_or();
+    }
+
+    /// Transforms `x AND NOT x` into `false` and `x OR NOT x` into `true`, provided that `x` is
+    /// neither nullable nor could error.
+    fn reduce_complements(&mut self, column_types: &[ColumnType]) {
+        if let MirScalarExpr::CallVariadic {
+            func: func @ (VariadicFunc::And | VariadicFunc::Or),
+            exprs,
+        } = self
+        {
+            let has_complement = exprs.iter().any(|e| match e {
+                MirScalarExpr::CallUnary {
+                    func: UnaryFunc::Not(func::Not),
+                    expr,
+                } => {
+                    exprs.contains(expr) && !expr.typ(column_types).nullable && !expr.could_error()
+                }
+                _ => false,
+            });
+            if has_complement {
+                *self = func.zero_of_and_or();
+            }
+        }
+    }
+
+    /// Decompose an IsNull expression into a disjunction of
+    /// simpler expressions.
+    ///
+    /// Assumes that `self` is the expression inside of an IsNull.
+    /// Returns `Some(expressions)` if the outer IsNull is to be
+    /// replaced by some other expression. Note: if it returns
+    /// None, it might still have mutated *self.
+    fn decompose_is_null(&mut self) -> Option<MirScalarExpr> {
+        // TODO: allow simplification of unmaterializable functions
+
+        match self {
             MirScalarExpr::CallUnary {
                 func,
                 expr: inner_expr,
@@ -1389,6 +2942,21 @@ impl MirScalarExpr {
                         exprs,
                     });
                 }
+                // coalesce(<expr1>, ..., <exprn>) IS NULL can be simplified to
+                // (<expr1> IS NULL) AND ... AND (<exprn> IS NULL).
+                // `coalesce` doesn't evaluate the arguments after the first non-null one, so an
+                // error in an earlier argument must not be masked by a later non-null one.
+                if *func == VariadicFunc::Coalesce
+                    && exprs
+                        .split_last()
+                        .map_or(false, |(_, init)| init.iter().all(|e| !e.could_error()))
+                {
+                    let exprs = exprs.into_iter().map(|e| e.take().call_is_null()).collect();
+                    return Some(MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::And,
+                        exprs,
+                    });
+                }
             }
             _ => {}
         }
@@ -1738,6 +3306,11 @@ impl MirScalarExpr {
         }
     }
 
+    /// True iff `self` has type `expected` in the context of `column_types`.
+    pub fn has_type(&self, column_types: &[ColumnType], expected: &ColumnType) -> bool {
+        self.typ(column_types) == *expected
+    }
+
     pub fn eval<'a>(
         &'a self,
         datums: &[Datum<'a>],
@@ -1772,14 +3345,50 @@ impl MirScalarExpr {
         }
     }
 
-    /// True iff the expression contains
-    /// `UnmaterializableFunc::MzNow`.
-    pub fn contains_temporal(&self) -> bool {
-        let mut contains = false;
-        #[allow(deprecated)]
-        self.visit_post_nolimit(&mut |e| {
-            if let MirScalarExpr::CallUnmaterializable(UnmaterializableFunc::MzNow) = e {
-                contains = true;
+    /// Like [`MirScalarExpr::eval`], but returns an error instead of overflowing the stack when
+    /// `self` is nested more deeply than [`crate::RECURSION_LIMIT`].
+    ///
+    /// `eval` recurses through the function implementations, so the depth is checked with a
+    /// stack-safe visit before evaluating.
+    pub fn eval_checked<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+    ) -> Result<Datum<'a>, EvalError> {
+        self.visit_pre(&mut |_| {})
+            .map_err(|e| EvalError::Internal(e.to_string()))?;
+        self.eval(datums, temp_storage)
+    }
+
+    /// Evaluates `self`, which must not reference any columns or unmaterializable functions, to
+    /// a constant.
+    ///
+    /// Unlike calling [`MirScalarExpr::eval`] with no datums, a stray column reference results in
+    /// an internal error instead of a panic.
+    pub fn eval_constant<'a>(&'a self, temp_storage: &'a RowArena) -> Result<Datum<'a>, EvalError> {
+        if self.contains_column() {
+            return Err(EvalError::Internal(format!(
+                "cannot evaluate expression with column references as a constant: {}",
+                self
+            )));
+        }
+        if self.contains_unmaterializable() {
+            return Err(EvalError::Internal(format!(
+                "cannot evaluate expression with unmaterializable functions as a constant: {}",
+                self
+            )));
+        }
+        self.eval(&[], temp_storage)
+    }
+
+    /// True iff the expression contains
+    /// `UnmaterializableFunc::MzNow`.
+    pub fn contains_temporal(&self) -> bool {
+        let mut contains = false;
+        #[allow(deprecated)]
+        self.visit_post_nolimit(&mut |e| {
+            if let MirScalarExpr::CallUnmaterializable(UnmaterializableFunc::MzNow) = e {
+                contains = true;
             }
         });
         contains
@@ -1809,6 +3418,149 @@ impl MirScalarExpr {
         contains
     }
 
+    /// True iff the expression contains an error literal, i.e., an error that is already known
+    /// statically.
+    ///
+    /// Unlike [`MirScalarExpr::could_error`], this ignores errors that could only occur at
+    /// runtime.
+    pub fn contains_error_literal(&self) -> bool {
+        let mut contains = false;
+        #[allow(deprecated)]
+        self.visit_post_nolimit(&mut |e| {
+            if let MirScalarExpr::Literal(Err(_), _) = e {
+                contains = true;
+            }
+        });
+        contains
+    }
+
+    /// Collects the errors of all error literals in `self`, in left-to-right order.
+    pub fn error_literals(&self) -> Vec<&EvalError> {
+        let mut errors = Vec::new();
+        let mut todo = vec![self];
+        while let Some(expr) = todo.pop() {
+            match expr {
+                MirScalarExpr::Literal(Err(err), _) => errors.push(err),
+                MirScalarExpr::Column(_)
+                | MirScalarExpr::Literal(Ok(_), _)
+                | MirScalarExpr::CallUnmaterializable(_) => {}
+                MirScalarExpr::CallUnary { expr, .. } => todo.push(expr),
+                MirScalarExpr::CallBinary { expr1, expr2, .. } => todo.extend([&**expr2, &**expr1]),
+                MirScalarExpr::CallVariadic { exprs, .. } => todo.extend(exprs.iter().rev()),
+                MirScalarExpr::If { cond, then, els } => todo.extend([&**els, &**then, &**cond]),
+            }
+        }
+        errors
+    }
+
+    /// True iff `self` references none of `loop_columns` and is deterministic, so that it
+    /// evaluates to the same result for every row that differs only in those columns, and can
+    /// be hoisted out of a loop over such rows.
+    pub fn is_loop_invariant(&self, loop_columns: &BTreeSet<usize>) -> bool {
+        self.support().is_disjoint(loop_columns) && !self.contains_unmaterializable()
+    }
+
+    /// The number of distinct subexpressions of `self` that occur more than once, which
+    /// indicates whether common subexpression elimination would be worthwhile.
+    ///
+    /// Columns, literals, and calls to unmaterializable functions are not counted, nor are the
+    /// subexpressions within the repeated occurrences of a repeated subexpression.
+    pub fn repeated_subexpr_count(&self) -> usize {
+        let mut counts = BTreeMap::new();
+        let mut todo = vec![self];
+        while let Some(expr) = todo.pop() {
+            let children = match expr {
+                MirScalarExpr::Column(_)
+                | MirScalarExpr::Literal(..)
+                | MirScalarExpr::CallUnmaterializable(_) => continue,
+                MirScalarExpr::CallUnary { expr, .. } => vec![&**expr],
+                MirScalarExpr::CallBinary { expr1, expr2, .. } => vec![&**expr1, &**expr2],
+                MirScalarExpr::CallVariadic { exprs, .. } => exprs.iter().collect(),
+                MirScalarExpr::If { cond, then, els } => vec![&**cond, &**then, &**els],
+            };
+            let count = counts.entry(expr).or_insert(0);
+            *count += 1;
+            if *count == 1 {
+                todo.extend(children);
+            }
+        }
+        counts.values().filter(|count| **count > 1).count()
+    }
+
+    /// Counts the occurrences of each distinct subexpression of `self`, including `self` itself.
+    ///
+    /// Columns and literals are not counted, as they are not worth eliminating. Unlike
+    /// [`MirScalarExpr::repeated_subexpr_count`], subexpressions nested within repeated
+    /// subexpressions are counted once per occurrence.
+    pub fn subexpression_counts(
+        &self,
+    ) -> Result<BTreeMap<MirScalarExpr, usize>, RecursionLimitError> {
+        let mut counts = BTreeMap::new();
+        self.visit_post(&mut |e: &MirScalarExpr| {
+            if !matches!(e, MirScalarExpr::Column(_) | MirScalarExpr::Literal(..)) {
+                *counts.entry(e.clone()).or_insert(0) += 1;
+            }
+        })?;
+        Ok(counts)
+    }
+
+    /// Returns the path to the first node, in pre-order, at which `self` and `other` differ, or
+    /// `None` if they are equal.
+    ///
+    /// A path is the sequence of child indexes leading from the root to the node, so an empty
+    /// path means that the roots themselves differ, e.g., because they call different functions
+    /// or call a variadic function with a different number of arguments.
+    pub fn structural_diff(&self, other: &MirScalarExpr) -> Option<Vec<usize>> {
+        use MirScalarExpr::*;
+        let children = match (self, other) {
+            (CallUnary { func: f1, expr: e1 }, CallUnary { func: f2, expr: e2 }) if f1 == f2 => {
+                vec![(&**e1, &**e2)]
+            }
+            (
+                CallBinary {
+                    func: f1,
+                    expr1: a1,
+                    expr2: b1,
+                },
+                CallBinary {
+                    func: f2,
+                    expr1: a2,
+                    expr2: b2,
+                },
+            ) if f1 == f2 => vec![(&**a1, &**a2), (&**b1, &**b2)],
+            (
+                CallVariadic {
+                    func: f1,
+                    exprs: e1,
+                },
+                CallVariadic {
+                    func: f2,
+                    exprs: e2,
+                },
+            ) if f1 == f2 && e1.len() == e2.len() => e1.iter().zip(e2.iter()).collect(),
+            (
+                If {
+                    cond: c1,
+                    then: t1,
+                    els: e1,
+                },
+                If {
+                    cond: c2,
+                    then: t2,
+                    els: e2,
+                },
+            ) => vec![(&**c1, &**c2), (&**t1, &**t2), (&**e1, &**e2)],
+            _ if self == other => return None,
+            _ => return Some(vec![]),
+        };
+        children.into_iter().enumerate().find_map(|(i, (c1, c2))| {
+            mz_ore::stack::maybe_grow(|| c1.structural_diff(c2)).map(|mut path| {
+                path.insert(0, i);
+                path
+            })
+        })
+    }
+
     pub fn size(&self) -> Result<usize, RecursionLimitError> {
         let mut size = 0;
         self.visit_post(&mut |_: &MirScalarExpr| {
@@ -1816,6 +3568,22 @@ impl MirScalarExpr {
         })?;
         Ok(size)
     }
+
+    /// Returns the number of nodes on the longest path from `self` to a leaf, so that a
+    /// column or literal has depth 1.
+    pub fn depth(&self) -> Result<usize, RecursionLimitError> {
+        // The depths of the visited subtrees that are still waiting for their parent. As
+        // children are visited before their parent, the last `n` entries belong to the `n`
+        // children of the node being visited.
+        let mut depths = Vec::new();
+        self.visit_post(&mut |e: &MirScalarExpr| {
+            let mut children = 0;
+            e.visit_children(|_| children += 1);
+            let max_child = depths.drain(depths.len() - children..).max().unwrap_or(0);
+            depths.push(max_child + 1);
+        })?;
+        Ok(depths.pop().expect("visited the root"))
+    }
 }
 
 impl MirScalarExpr {
@@ -2002,6 +3770,11 @@ impl FilterCharacteristics {
         }
     }
 
+    /// Above this many literal inequalities, [`FilterCharacteristics::explain`] prints their count
+    /// as `i{n}` rather than repeating `i`.
+    const EXPLAIN_MAX_REPEATED_INEQUALITIES: usize = 3;
+
+    /// Renders the characteristics compactly for EXPLAIN, e.g., `eiif` or `ei12`.
     pub fn explain(&self) -> String {
         let mut e = "".to_owned();
         if self.literal_equality {
@@ -2013,8 +3786,12 @@ impl FilterCharacteristics {
         if self.is_null {
             e.push_str("n");
         }
-        for _ in 0..self.literal_inequality {
-            e.push_str("i");
+        if self.literal_inequality > Self::EXPLAIN_MAX_REPEATED_INEQUALITIES {
+            e.push_str(&format!("i{}", self.literal_inequality));
+        } else {
+            for _ in 0..self.literal_inequality {
+                e.push_str("i");
+            }
         }
         if self.any_filter {
             e.push_str("f");
@@ -2022,6 +3799,12 @@ impl FilterCharacteristics {
         e
     }
 
+    /// Whether the filters are unlikely to meaningfully narrow their input, i.e., nothing other
+    /// than `any_filter` is set. `any_filter` itself is not considered.
+    pub fn is_weak(&self) -> bool {
+        !self.literal_equality && !self.like && !self.is_null && self.literal_inequality == 0
+    }
+
     pub fn filter_characteristics(
         filters: &Vec<MirScalarExpr>,
     ) -> Result<FilterCharacteristics, RecursionLimitError> {
@@ -2456,6 +4239,181 @@ impl EvalError {
             _ => None,
         }
     }
+
+    /// Returns the five-character SQLSTATE code that best describes this
+    /// error.
+    ///
+    /// The codes follow PostgreSQL's assignments for the analogous errors.
+    pub fn sql_state(&self) -> &'static str {
+        match self {
+            EvalError::DivisionByZero => "22012",
+            EvalError::FloatOverflow
+            | EvalError::FloatUnderflow
+            | EvalError::NumericFieldOverflow
+            | EvalError::Float32OutOfRange(_)
+            | EvalError::Float64OutOfRange(_)
+            | EvalError::Int16OutOfRange(_)
+            | EvalError::Int32OutOfRange(_)
+            | EvalError::Int64OutOfRange(_)
+            | EvalError::UInt16OutOfRange(_)
+            | EvalError::UInt32OutOfRange(_)
+            | EvalError::UInt64OutOfRange(_)
+            | EvalError::OidOutOfRange(_)
+            | EvalError::CharOutOfRange
+            | EvalError::ComplexOutOfRange(_)
+            | EvalError::InfinityOutOfDomain(_)
+            | EvalError::NegativeOutOfDomain(_)
+            | EvalError::ZeroOutOfDomain(_)
+            | EvalError::OutOfDomain(..) => "22003",
+            EvalError::DateBinOutOfRange(_)
+            | EvalError::MzTimestampOutOfRange(_)
+            | EvalError::MzTimestampStepOverflow
+            | EvalError::IntervalOutOfRange(_)
+            | EvalError::TimestampCannotBeNan
+            | EvalError::TimestampOutOfRange
+            | EvalError::DateOutOfRange => "22008",
+            EvalError::InvalidBase64Equals
+            | EvalError::InvalidBase64Symbol(_)
+            | EvalError::InvalidBase64EndSequence
+            | EvalError::InvalidTimezone(_)
+            | EvalError::InvalidTimezoneInterval
+            | EvalError::InvalidTimezoneConversion
+            | EvalError::InvalidLayer { .. }
+            | EvalError::InvalidEncodingName(_)
+            | EvalError::InvalidHashAlgorithm(_)
+            | EvalError::InvalidJsonbCast { .. }
+            | EvalError::InvalidRegexFlag(_)
+            | EvalError::InvalidParameterValue(_)
+            | EvalError::UnknownUnits(_)
+            | EvalError::InvalidPrivileges(_) => "22023",
+            EvalError::IndexOutOfRange { .. }
+            | EvalError::InvalidArray(_)
+            | EvalError::IncompatibleArrayDimensions { .. }
+            | EvalError::ArrayFillWrongArraySubscripts => "2202E",
+            EvalError::Parse(_) | EvalError::ParseHex(_) => "22P02",
+            EvalError::InvalidByteSequence { .. } => "22021",
+            EvalError::InvalidRegex(_) => "2201B",
+            EvalError::NegSqrt => "2201F",
+            EvalError::LikeEscapeTooLong => "22019",
+            EvalError::UnterminatedLikeEscapeSequence => "22025",
+            EvalError::StringValueTooLong { .. } => "22001",
+            EvalError::MustNotBeNull(_) => "22004",
+            EvalError::InvalidRange(_) => "22000",
+            EvalError::MultipleRowsFromSubquery => "21000",
+            EvalError::CharacterNotValidForEncoding(_)
+            | EvalError::CharacterTooLargeForEncoding(_)
+            | EvalError::NullCharacterNotPermitted
+            | EvalError::LikePatternTooLong
+            | EvalError::LetRecLimitExceeded(_)
+            | EvalError::MaxArraySizeExceeded(_) => "54000",
+            EvalError::Unsupported { .. }
+            | EvalError::UnsupportedUnits(..)
+            | EvalError::MultidimensionalArrayRemovalNotSupported
+            | EvalError::MultiDimensionalArraySearch => "0A000",
+            EvalError::Undefined(_) => "42883",
+            EvalError::TypeFromOid(_) | EvalError::InvalidRoleId(_) => "42704",
+            EvalError::InvalidIdentifier { .. } => "42602",
+            EvalError::Internal(_) => "XX000",
+        }
+    }
+
+    /// Returns the coarse category of this error, e.g., for bucketing errors in metrics.
+    ///
+    /// The match is exhaustive on purpose, so that new variants have to be
+    /// categorized explicitly.
+    pub fn category(&self) -> EvalErrorCategory {
+        match self {
+            EvalError::DivisionByZero => EvalErrorCategory::DivisionByZero,
+            EvalError::CharacterTooLargeForEncoding(_)
+            | EvalError::DateBinOutOfRange(_)
+            | EvalError::FloatOverflow
+            | EvalError::FloatUnderflow
+            | EvalError::NumericFieldOverflow
+            | EvalError::Float32OutOfRange(_)
+            | EvalError::Float64OutOfRange(_)
+            | EvalError::Int16OutOfRange(_)
+            | EvalError::Int32OutOfRange(_)
+            | EvalError::Int64OutOfRange(_)
+            | EvalError::UInt16OutOfRange(_)
+            | EvalError::UInt32OutOfRange(_)
+            | EvalError::UInt64OutOfRange(_)
+            | EvalError::MzTimestampOutOfRange(_)
+            | EvalError::MzTimestampStepOverflow
+            | EvalError::OidOutOfRange(_)
+            | EvalError::IntervalOutOfRange(_)
+            | EvalError::TimestampCannotBeNan
+            | EvalError::TimestampOutOfRange
+            | EvalError::DateOutOfRange
+            | EvalError::CharOutOfRange
+            | EvalError::IndexOutOfRange { .. }
+            | EvalError::ComplexOutOfRange(_)
+            | EvalError::StringValueTooLong { .. }
+            | EvalError::MaxArraySizeExceeded(_) => EvalErrorCategory::OutOfRange,
+            EvalError::Parse(_)
+            | EvalError::ParseHex(_)
+            | EvalError::InvalidBase64Equals
+            | EvalError::InvalidBase64Symbol(_)
+            | EvalError::InvalidBase64EndSequence
+            | EvalError::InvalidIdentifier { .. } => EvalErrorCategory::Parse,
+            EvalError::CharacterNotValidForEncoding(_)
+            | EvalError::InvalidTimezone(_)
+            | EvalError::InvalidTimezoneInterval
+            | EvalError::InvalidTimezoneConversion
+            | EvalError::InvalidLayer { .. }
+            | EvalError::InvalidArray(_)
+            | EvalError::InvalidEncodingName(_)
+            | EvalError::InvalidHashAlgorithm(_)
+            | EvalError::InvalidByteSequence { .. }
+            | EvalError::InvalidJsonbCast { .. }
+            | EvalError::InvalidRegex(_)
+            | EvalError::InvalidRegexFlag(_)
+            | EvalError::InvalidParameterValue(_)
+            | EvalError::NegSqrt
+            | EvalError::NullCharacterNotPermitted
+            | EvalError::UnknownUnits(_)
+            | EvalError::UnterminatedLikeEscapeSequence
+            | EvalError::InfinityOutOfDomain(_)
+            | EvalError::NegativeOutOfDomain(_)
+            | EvalError::ZeroOutOfDomain(_)
+            | EvalError::OutOfDomain(..)
+            | EvalError::LikePatternTooLong
+            | EvalError::LikeEscapeTooLong
+            | EvalError::IncompatibleArrayDimensions { .. }
+            | EvalError::InvalidRange(_)
+            | EvalError::InvalidPrivileges(_)
+            | EvalError::MustNotBeNull(_)
+            | EvalError::ArrayFillWrongArraySubscripts => EvalErrorCategory::Domain,
+            EvalError::Unsupported { .. }
+            | EvalError::UnsupportedUnits(..)
+            | EvalError::MultidimensionalArrayRemovalNotSupported
+            | EvalError::MultiDimensionalArraySearch => EvalErrorCategory::Unsupported,
+            EvalError::Internal(_) => EvalErrorCategory::Internal,
+            EvalError::MultipleRowsFromSubquery
+            | EvalError::Undefined(_)
+            | EvalError::TypeFromOid(_)
+            | EvalError::InvalidRoleId(_)
+            | EvalError::LetRecLimitExceeded(_) => EvalErrorCategory::Other,
+        }
+    }
+}
+
+/// A coarse classification of [`EvalError`]s, as returned by [`EvalError::category`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum EvalErrorCategory {
+    /// A value does not fit into the range of its type.
+    OutOfRange,
+    /// A division or modulo by zero.
+    DivisionByZero,
+    /// A string could not be parsed or decoded.
+    Parse,
+    /// An argument lies outside the domain of a function.
+    Domain,
+    /// The requested operation is not supported.
+    Unsupported,
+    /// An internal error that indicates a bug.
+    Internal,
+    /// Any other error.
+    Other,
 }
 
 impl std::error::Error for EvalError {}
@@ -2787,6 +4745,7 @@ impl RustType<ProtoDims> for (usize, usize) {
 #[cfg(test)]
 mod tests {
     use mz_proto::protobuf_roundtrip;
+    use mz_repr::adt::varchar::VarCharMaxLength;
 
     use super::*;
 
@@ -2796,6 +4755,7 @@ mod tests {
             ScalarType::Int64.nullable(true),
             ScalarType::Int64.nullable(true),
             ScalarType::Int64.nullable(false),
+            ScalarType::String.nullable(true),
         ];
         let col = MirScalarExpr::Column;
         let err = |e| MirScalarExpr::literal(Err(e), ScalarType::Int64);
@@ -2881,6 +4841,354 @@ mod tests {
                 },
                 output: err(EvalError::DivisionByZero),
             },
+            TestCase {
+                // A CASE whose branches all return the same literal collapses to that literal.
+                input: col(0).call_binary(lit(1), BinaryFunc::Eq).if_then_else(
+                    lit(7),
+                    col(0).call_binary(lit(2), BinaryFunc::Eq).if_then_else(
+                        lit(7),
+                        col(1).call_binary(lit(3), BinaryFunc::Lt).if_then_else(
+                            lit(7),
+                            col(2)
+                                .call_binary(lit(4), BinaryFunc::Gt)
+                                .if_then_else(lit(7), lit(7)),
+                        ),
+                    ),
+                ),
+                output: lit(7),
+            },
+            TestCase {
+                // CASE WHEN #0 = 1 THEN #1 IS NULL ELSE #2 IS NULL END
+                //   --> (CASE WHEN #0 = 1 THEN #1 ELSE #2 END) IS NULL
+                input: col(0)
+                    .call_binary(lit(1), BinaryFunc::Eq)
+                    .if_then_else(col(1).call_is_null(), col(2).call_is_null()),
+                output: col(0)
+                    .call_binary(lit(1), BinaryFunc::Eq)
+                    .if_then_else(col(1), col(2))
+                    .call_is_null(),
+            },
+            TestCase {
+                // The branches have different types, so they can't be combined.
+                input: col(0)
+                    .call_binary(lit(1), BinaryFunc::Eq)
+                    .if_then_else(col(1).call_is_null(), col(3).call_is_null()),
+                output: col(0)
+                    .call_binary(lit(1), BinaryFunc::Eq)
+                    .if_then_else(col(1).call_is_null(), col(3).call_is_null()),
+            },
+            TestCase {
+                // Different functions can't be hoisted.
+                input: col(0).call_binary(lit(1), BinaryFunc::Eq).if_then_else(
+                    col(1).call_is_null(),
+                    col(2).call_unary(UnaryFunc::IsTrue(func::IsTrue)),
+                ),
+                output: col(0).call_binary(lit(1), BinaryFunc::Eq).if_then_else(
+                    col(1).call_is_null(),
+                    col(2).call_unary(UnaryFunc::IsTrue(func::IsTrue)),
+                ),
+            },
+            TestCase {
+                // `-x` can error on overflow, so it isn't hoisted.
+                input: col(0).call_binary(lit(1), BinaryFunc::Eq).if_then_else(
+                    col(1).call_unary(UnaryFunc::NegInt64(func::NegInt64)),
+                    col(2).call_unary(UnaryFunc::NegInt64(func::NegInt64)),
+                ),
+                output: col(0).call_binary(lit(1), BinaryFunc::Eq).if_then_else(
+                    col(1).call_unary(UnaryFunc::NegInt64(func::NegInt64)),
+                    col(2).call_unary(UnaryFunc::NegInt64(func::NegInt64)),
+                ),
+            },
+            TestCase {
+                // coalesce(CASE WHEN #0 = 1 THEN #2 ELSE NULL END, #1)
+                //   --> CASE WHEN #0 = 1 THEN #2 ELSE #1 END
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Coalesce,
+                    exprs: vec![
+                        col(0)
+                            .call_binary(lit(1), BinaryFunc::Eq)
+                            .if_then_else(col(2), null()),
+                        col(1),
+                    ],
+                },
+                output: col(0)
+                    .call_binary(lit(1), BinaryFunc::Eq)
+                    .if_then_else(col(2), col(1)),
+            },
+            TestCase {
+                // The `If` stays behind the arguments that precede it, and the arguments after
+                // it move into its `ELSE` branch.
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Coalesce,
+                    exprs: vec![
+                        col(1),
+                        col(0)
+                            .call_binary(lit(1), BinaryFunc::Eq)
+                            .if_then_else(col(2), null()),
+                        col(0),
+                        lit(3),
+                    ],
+                },
+                output: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Coalesce,
+                    exprs: vec![
+                        col(1),
+                        col(0).call_binary(lit(1), BinaryFunc::Eq).if_then_else(
+                            col(2),
+                            MirScalarExpr::CallVariadic {
+                                func: VariadicFunc::Coalesce,
+                                exprs: vec![col(0), lit(3)],
+                            },
+                        ),
+                    ],
+                },
+            },
+            TestCase {
+                // A nullable `THEN` branch can fall through to later arguments, so the
+                // coalesce is left alone.
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Coalesce,
+                    exprs: vec![
+                        col(0)
+                            .call_binary(lit(1), BinaryFunc::Eq)
+                            .if_then_else(col(1), null()),
+                        col(2),
+                    ],
+                },
+                output: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Coalesce,
+                    exprs: vec![
+                        col(0)
+                            .call_binary(lit(1), BinaryFunc::Eq)
+                            .if_then_else(col(1), null()),
+                        col(2),
+                    ],
+                },
+            },
+            TestCase {
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Greatest,
+                    exprs: vec![col(2), lit(i64::MAX)],
+                },
+                output: lit(i64::MAX),
+            },
+            TestCase {
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Greatest,
+                    exprs: vec![col(2), lit(100)],
+                },
+                output: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Greatest,
+                    exprs: vec![col(2), lit(100)],
+                },
+            },
+            TestCase {
+                // `least` ignores NULLs, so the column's nullability doesn't matter.
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Least,
+                    exprs: vec![col(0), lit(i64::MIN), col(1)],
+                },
+                output: lit(i64::MIN),
+            },
+            TestCase {
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Least,
+                    exprs: vec![col(0), lit(i64::MAX)],
+                },
+                output: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Least,
+                    exprs: vec![col(0), lit(i64::MAX)],
+                },
+            },
+            TestCase {
+                input: col(2).call_binary(lit(0), BinaryFunc::BitAndInt64),
+                output: lit(0),
+            },
+            TestCase {
+                // NULL & 0 is NULL, so a nullable operand can't be dropped.
+                input: col(0).call_binary(lit(0), BinaryFunc::BitAndInt64),
+                output: col(0).call_binary(lit(0), BinaryFunc::BitAndInt64),
+            },
+            TestCase {
+                input: col(0).call_binary(lit(-1), BinaryFunc::BitAndInt64),
+                output: col(0),
+            },
+            TestCase {
+                input: col(0).call_binary(lit(0), BinaryFunc::BitOrInt64),
+                output: col(0),
+            },
+            TestCase {
+                input: col(2).call_binary(lit(-1), BinaryFunc::BitOrInt64),
+                output: lit(-1),
+            },
+            TestCase {
+                input: lit(0).call_binary(col(0), BinaryFunc::BitXorInt64),
+                output: col(0),
+            },
+            TestCase {
+                input: col(0).call_binary(lit(0), BinaryFunc::AddInt64),
+                output: col(0),
+            },
+            TestCase {
+                input: lit(0).call_binary(col(0), BinaryFunc::AddInt64),
+                output: col(0),
+            },
+            TestCase {
+                input: col(0).call_binary(lit(0), BinaryFunc::SubInt64),
+                output: col(0),
+            },
+            TestCase {
+                // 0 - x is a negation, not an identity.
+                input: lit(0).call_binary(col(0), BinaryFunc::SubInt64),
+                output: lit(0).call_binary(col(0), BinaryFunc::SubInt64),
+            },
+            TestCase {
+                input: lit(1).call_binary(col(0), BinaryFunc::MulInt64),
+                output: col(0),
+            },
+            TestCase {
+                input: col(2).call_binary(lit(0), BinaryFunc::MulInt64),
+                output: lit(0),
+            },
+            TestCase {
+                // NULL * 0 is NULL, so a nullable operand can't be dropped.
+                input: col(0).call_binary(lit(0), BinaryFunc::MulInt64),
+                output: col(0).call_binary(lit(0), BinaryFunc::MulInt64),
+            },
+            TestCase {
+                // coalesce(#0, #1) IS NULL --> #0 IS NULL AND #1 IS NULL
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Coalesce,
+                    exprs: vec![col(0), col(1)],
+                }
+                .call_is_null(),
+                output: col(0).call_is_null().and(col(1).call_is_null()),
+            },
+            TestCase {
+                // coalesce(#0, #1) IS NOT NULL --> #0 IS NOT NULL OR #1 IS NOT NULL
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Coalesce,
+                    exprs: vec![col(0), col(1)],
+                }
+                .call_is_null()
+                .not(),
+                output: col(0).call_is_null().not().or(col(1).call_is_null().not()),
+            },
+            TestCase {
+                // #0 = 5 AND #0 > 3 --> #0 = 5
+                input: col(0)
+                    .call_binary(lit(5), BinaryFunc::Eq)
+                    .and(col(0).call_binary(lit(3), BinaryFunc::Gt)),
+                output: col(0).call_binary(lit(5), BinaryFunc::Eq),
+            },
+            TestCase {
+                // #0 = 5 AND #0 < 3 --> false
+                input: col(0)
+                    .call_binary(lit(5), BinaryFunc::Eq)
+                    .and(col(0).call_binary(lit(3), BinaryFunc::Lt)),
+                output: MirScalarExpr::literal_false(),
+            },
+            TestCase {
+                // #0 > 5 AND #0 > 3 --> #0 > 5
+                input: col(0)
+                    .call_binary(lit(5), BinaryFunc::Gt)
+                    .and(col(0).call_binary(lit(3), BinaryFunc::Gt)),
+                output: col(0).call_binary(lit(5), BinaryFunc::Gt),
+            },
+            TestCase {
+                // 5 <= #0 AND #0 > 5 AND #0 < 9 AND #0 <= 9 --> #0 < 9 AND #0 > 5
+                input: MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::And,
+                    exprs: vec![
+                        lit(5).call_binary(col(0), BinaryFunc::Lte),
+                        col(0).call_binary(lit(5), BinaryFunc::Gt),
+                        col(0).call_binary(lit(9), BinaryFunc::Lt),
+                        col(0).call_binary(lit(9), BinaryFunc::Lte),
+                    ],
+                },
+                output: col(0)
+                    .call_binary(lit(9), BinaryFunc::Lt)
+                    .and(col(0).call_binary(lit(5), BinaryFunc::Gt)),
+            },
+            TestCase {
+                // Bounds on different columns are left alone.
+                input: col(0)
+                    .call_binary(lit(5), BinaryFunc::Gt)
+                    .and(col(1).call_binary(lit(3), BinaryFunc::Gt)),
+                output: col(0)
+                    .call_binary(lit(5), BinaryFunc::Gt)
+                    .and(col(1).call_binary(lit(3), BinaryFunc::Gt)),
+            },
+            TestCase {
+                // #2 >= 5 AND #2 <= 5 is satisfiable ...
+                input: col(2)
+                    .call_binary(lit(5), BinaryFunc::Gte)
+                    .and(col(2).call_binary(lit(5), BinaryFunc::Lte)),
+                output: col(2)
+                    .call_binary(lit(5), BinaryFunc::Lte)
+                    .and(col(2).call_binary(lit(5), BinaryFunc::Gte)),
+            },
+            TestCase {
+                // ... but #2 > 5 AND #2 <= 5 is not.
+                input: col(2)
+                    .call_binary(lit(5), BinaryFunc::Gt)
+                    .and(col(2).call_binary(lit(5), BinaryFunc::Lte)),
+                output: MirScalarExpr::literal_false(),
+            },
+            TestCase {
+                // #0 > 5 AND #0 < 3 is NULL rather than false if #0 is NULL.
+                input: col(0)
+                    .call_binary(lit(5), BinaryFunc::Gt)
+                    .and(col(0).call_binary(lit(3), BinaryFunc::Lt)),
+                output: MirScalarExpr::literal_null(ScalarType::Bool).and(col(0).call_is_null()),
+            },
+            TestCase {
+                input: col(2).call_binary(col(2), BinaryFunc::Eq),
+                output: MirScalarExpr::literal_true(),
+            },
+            TestCase {
+                input: col(0).call_binary(col(0), BinaryFunc::Eq),
+                output: MirScalarExpr::literal_null(ScalarType::Bool)
+                    .or(col(0).call_is_null().not()),
+            },
+            TestCase {
+                // The rewrite must not swallow a potential error.
+                input: col(2)
+                    .call_binary(lit(0), BinaryFunc::DivInt64)
+                    .call_binary(
+                        col(2).call_binary(lit(0), BinaryFunc::DivInt64),
+                        BinaryFunc::Eq,
+                    ),
+                output: col(2)
+                    .call_binary(lit(0), BinaryFunc::DivInt64)
+                    .call_binary(
+                        col(2).call_binary(lit(0), BinaryFunc::DivInt64),
+                        BinaryFunc::Eq,
+                    ),
+            },
+            TestCase {
+                input: col(2).call_binary(col(2), BinaryFunc::NotEq),
+                output: MirScalarExpr::literal_false(),
+            },
+            TestCase {
+                input: col(0).call_binary(col(0), BinaryFunc::NotEq),
+                output: MirScalarExpr::literal_null(ScalarType::Bool).and(col(0).call_is_null()),
+            },
+            TestCase {
+                // The rewrite must not swallow a potential error.
+                input: col(2)
+                    .call_binary(lit(0), BinaryFunc::DivInt64)
+                    .call_binary(
+                        col(2).call_binary(lit(0), BinaryFunc::DivInt64),
+                        BinaryFunc::NotEq,
+                    ),
+                output: col(2)
+                    .call_binary(lit(0), BinaryFunc::DivInt64)
+                    .call_binary(
+                        col(2).call_binary(lit(0), BinaryFunc::DivInt64),
+                        BinaryFunc::NotEq,
+                    ),
+            },
         ];
 
         for tc in test_cases {
@@ -2893,24 +5201,1897 @@ mod tests {
                 actual,
                 tc.output
             );
+            // The reduced expression must still be well-typed.
+            actual.typ(&relation_type);
         }
     }
 
-    proptest! {
-        #[mz_ore::test]
-        fn mir_scalar_expr_protobuf_roundtrip(expect in any::<MirScalarExpr>()) {
-            let actual = protobuf_roundtrip::<_, ProtoMirScalarExpr>(&expect);
-            assert!(actual.is_ok());
-            assert_eq!(actual.unwrap(), expect);
+    #[mz_ore::test]
+    fn test_reduce_implied_comparisons() {
+        let relation_type = vec![ScalarType::Float64.nullable(false)];
+        let lit = |f: f64| MirScalarExpr::literal_ok(Datum::Float64(f.into()), ScalarType::Float64);
+
+        // #0 = 0.0 AND #0 = -0.0: each binding implies the other, but only one may be dropped.
+        let input = MirScalarExpr::column(0)
+            .call_binary(lit(0.0), BinaryFunc::Eq)
+            .and(MirScalarExpr::column(0).call_binary(lit(-0.0), BinaryFunc::Eq));
+        let mut actual = input.clone();
+        actual.reduce(&relation_type);
+        assert_ne!(actual, MirScalarExpr::literal_true());
+        let arena = RowArena::new();
+        for f in [0.0, -0.0, 1.0] {
+            let datums = [Datum::Float64(f.into())];
+            assert_eq!(
+                actual.eval(&datums, &arena),
+                input.eval(&datums, &arena),
+                "input: {input}\nactual: {actual}\n#0: {f}"
+            );
         }
     }
 
-    proptest! {
-        #[mz_ore::test]
-        fn domain_limit_protobuf_roundtrip(expect in any::<DomainLimit>()) {
-            let actual = protobuf_roundtrip::<_, ProtoDomainLimit>(&expect);
-            assert!(actual.is_ok());
-            assert_eq!(actual.unwrap(), expect);
+    #[mz_ore::test]
+    fn test_simplify_boolean() {
+        let relation_type = vec![
+            ScalarType::Bool.nullable(true),
+            ScalarType::Bool.nullable(true),
+            ScalarType::Bool.nullable(false),
+            ScalarType::Int64.nullable(false),
+        ];
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let t = MirScalarExpr::literal_true;
+        let f = MirScalarExpr::literal_false;
+
+        struct TestCase {
+            input: MirScalarExpr,
+            output: MirScalarExpr,
+        }
+
+        let test_cases = vec![
+            // x AND true --> x
+            TestCase {
+                input: col(0).and(t()),
+                output: col(0),
+            },
+            // x OR false --> x
+            TestCase {
+                input: col(0).or(f()),
+                output: col(0),
+            },
+            // x AND false --> false
+            TestCase {
+                input: col(0).and(f()),
+                output: f(),
+            },
+            // NOT NOT x --> x
+            TestCase {
+                input: col(0).not().not(),
+                output: col(0),
+            },
+            // NOT (x AND y) --> NOT x OR NOT y
+            TestCase {
+                input: col(0).and(col(1)).not(),
+                output: col(0).not().or(col(1).not()),
+            },
+            // NOT (x OR y) --> NOT x AND NOT y
+            TestCase {
+                input: col(0).or(col(1)).not(),
+                output: col(0).not().and(col(1).not()),
+            },
+            // x OR (x AND y) --> x
+            TestCase {
+                input: col(0).or(col(0).and(col(1))),
+                output: col(0),
+            },
+            // (x AND y) OR (x AND z) --> x AND (y OR z)
+            TestCase {
+                input: col(0).and(col(1)).or(col(0).and(col(2))),
+                output: col(0).and(col(1).or(col(2))),
+            },
+            // x AND NOT x --> false, but only for non-nullable x
+            TestCase {
+                input: col(2).and(col(2).not()),
+                output: f(),
+            },
+            TestCase {
+                input: col(2).or(col(2).not()),
+                output: t(),
+            },
+            TestCase {
+                input: col(0).and(col(0).not()),
+                output: col(0).and(col(0).not()),
+            },
+            // Arithmetic is left alone.
+            TestCase {
+                input: col(3)
+                    .call_binary(
+                        lit(1).call_binary(lit(2), BinaryFunc::AddInt64),
+                        BinaryFunc::Eq,
+                    )
+                    .and(t()),
+                output: col(3).call_binary(
+                    lit(1).call_binary(lit(2), BinaryFunc::AddInt64),
+                    BinaryFunc::Eq,
+                ),
+            },
+        ];
+
+        for tc in test_cases {
+            let mut actual = tc.input.clone();
+            actual.simplify_boolean(&relation_type);
+            assert!(
+                actual == tc.output,
+                "input: {}\nactual: {}\nexpected: {}",
+                tc.input,
+                actual,
+                tc.output
+            );
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_to_cnf() {
+        let col = MirScalarExpr::Column;
+        let limit = MirScalarExpr::CNF_SIZE_LIMIT;
+
+        // (#0 AND #1) OR #2 --> (#0 OR #2) AND (#1 OR #2)
+        let mut expr = col(0).and(col(1)).or(col(2));
+        expr.to_cnf(limit);
+        assert_eq!(expr, col(0).or(col(2)).and(col(1).or(col(2))));
+
+        // #0 OR (#1 AND #2) --> (#0 OR #1) AND (#0 OR #2)
+        let mut expr = col(0).or(col(1).and(col(2)));
+        expr.to_cnf(limit);
+        assert_eq!(expr, col(0).or(col(1)).and(col(0).or(col(2))));
+
+        // NOT(NOT(#0) AND (#1 OR #2)) --> #0 OR (NOT(#1) AND NOT(#2))
+        //                             --> (#0 OR NOT(#1)) AND (#0 OR NOT(#2))
+        let mut expr = col(0).not().and(col(1).or(col(2))).not();
+        expr.to_cnf(limit);
+        assert_eq!(expr, col(0).or(col(1).not()).and(col(0).or(col(2).not())));
+
+        // Already in CNF.
+        let cnf = col(0).or(col(1)).and(col(2));
+        let mut expr = cnf.clone();
+        expr.to_cnf(limit);
+        assert_eq!(expr, cnf);
+
+        // (#0 AND #1) OR (#2 AND #3) OR ... would have 2^16 clauses, so it is left unchanged.
+        let dnf = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Or,
+            exprs: (0..16).map(|i| col(2 * i).and(col(2 * i + 1))).collect(),
+        };
+        let mut expr = dnf.clone();
+        expr.to_cnf(limit);
+        assert_eq!(expr, dnf);
+
+        // The size limit is up to the caller: the CNF of (#0 AND #1) OR (#2 AND #3) is an AND of
+        // four ORs of two columns each, i.e., 13 nodes.
+        let dnf = col(0).and(col(1)).or(col(2).and(col(3)));
+        let mut expr = dnf.clone();
+        expr.to_cnf(12);
+        assert_eq!(expr, dnf);
+        expr.to_cnf(13);
+        assert_eq!(expr.size().unwrap(), 13);
+
+        // `usize::MAX` means no limit.
+        let mut expr = dnf.clone();
+        expr.to_cnf(usize::MAX);
+        assert_eq!(expr.size().unwrap(), 13);
+    }
+
+    #[mz_ore::test]
+    fn test_to_dnf() {
+        let col = MirScalarExpr::Column;
+        let limit = MirScalarExpr::CNF_SIZE_LIMIT;
+
+        // (#0 OR #1) AND #2 --> (#0 AND #2) OR (#1 AND #2)
+        let mut expr = col(0).or(col(1)).and(col(2));
+        expr.to_dnf(limit);
+        assert_eq!(expr, col(0).and(col(2)).or(col(1).and(col(2))));
+
+        // NOT(#0 OR NOT(#1 OR #2)) --> NOT(#0) AND (#1 OR #2)
+        //                          --> (NOT(#0) AND #1) OR (NOT(#0) AND #2)
+        let mut expr = col(0).or(col(1).or(col(2)).not()).not();
+        expr.to_dnf(limit);
+        assert_eq!(expr, col(0).not().and(col(1)).or(col(0).not().and(col(2))));
+
+        // Already in DNF.
+        let dnf = col(0).and(col(1)).or(col(2));
+        let mut expr = dnf.clone();
+        expr.to_dnf(limit);
+        assert_eq!(expr, dnf);
+
+        // (#0 OR #1) AND (#2 OR #3) AND ... would have 2^16 terms, so it is left unchanged.
+        let cnf = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs: (0..16).map(|i| col(2 * i).or(col(2 * i + 1))).collect(),
+        };
+        let mut expr = cnf.clone();
+        expr.to_dnf(limit);
+        assert_eq!(expr, cnf);
+
+        // The size limit is up to the caller: the DNF of (#0 OR #1) AND (#2 OR #3) is an OR of
+        // four ANDs of two columns each, i.e., 13 nodes.
+        let cnf = col(0).or(col(1)).and(col(2).or(col(3)));
+        let mut expr = cnf.clone();
+        expr.to_dnf(12);
+        assert_eq!(expr, cnf);
+        expr.to_dnf(13);
+        assert_eq!(expr.size().unwrap(), 13);
+    }
+
+    #[mz_ore::test]
+    fn test_canonical_form() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // (#0 = 5 AND #1) AND #2 vs. #2 AND (#1 AND 5 = #0 AND #1)
+        let e1 = col(0)
+            .call_binary(lit(5), BinaryFunc::Eq)
+            .and(col(1))
+            .and(col(2));
+        let e2 = col(2).and(
+            col(1)
+                .and(lit(5).call_binary(col(0), BinaryFunc::Eq))
+                .and(col(1)),
+        );
+        assert_ne!(e1, e2);
+        assert_eq!(e1.canonical_form(), e2.canonical_form());
+
+        // Constants are not folded.
+        let expr = lit(1).call_binary(lit(2), BinaryFunc::AddInt64);
+        assert_eq!(expr.canonical_form(), expr);
+
+        // Non-commutative operands keep their order.
+        let e1 = col(0).call_binary(col(1), BinaryFunc::SubInt64);
+        let e2 = col(1).call_binary(col(0), BinaryFunc::SubInt64);
+        assert_ne!(e1.canonical_form(), e2.canonical_form());
+    }
+
+    #[mz_ore::test]
+    fn test_rewrite_first() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // (#0 + #1) * #0: only the first #0 in pre-order is rewritten.
+        let mut expr = col(0)
+            .call_binary(col(1), BinaryFunc::AddInt64)
+            .call_binary(col(0), BinaryFunc::MulInt64);
+        let rewritten = expr.rewrite_first(|e| {
+            if *e == col(0) {
+                *e = lit(7);
+                true
+            } else {
+                false
+            }
+        });
+        assert!(rewritten);
+        assert_eq!(
+            expr,
+            lit(7)
+                .call_binary(col(1), BinaryFunc::AddInt64)
+                .call_binary(col(0), BinaryFunc::MulInt64)
+        );
+
+        // No match leaves the expression unchanged.
+        let before = expr.clone();
+        assert!(!expr.rewrite_first(|e| *e == col(5)));
+        assert_eq!(expr, before);
+    }
+
+    #[mz_ore::test]
+    fn test_shift_columns() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // (#0 + #2) * 1 --> (#3 + #5) * 1
+        let mut expr = col(0)
+            .call_binary(col(2), BinaryFunc::AddInt64)
+            .call_binary(lit(1), BinaryFunc::MulInt64);
+        expr.shift_columns(3);
+        assert_eq!(
+            expr,
+            col(3)
+                .call_binary(col(5), BinaryFunc::AddInt64)
+                .call_binary(lit(1), BinaryFunc::MulInt64)
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_map_columns() {
+        let col = MirScalarExpr::Column;
+
+        // #0 + (#1 * #0), with even columns moved past an arity-10 prefix.
+        let mut expr = col(0).call_binary(
+            col(1).call_binary(col(0), BinaryFunc::MulInt64),
+            BinaryFunc::AddInt64,
+        );
+        let mut calls = 0;
+        expr.map_columns(|i| {
+            calls += 1;
+            if i % 2 == 0 {
+                i + 10
+            } else {
+                i
+            }
+        });
+        assert_eq!(
+            expr,
+            col(10).call_binary(
+                col(1).call_binary(col(10), BinaryFunc::MulInt64),
+                BinaryFunc::AddInt64,
+            )
+        );
+        // Once per column reference, not once per distinct column.
+        assert_eq!(calls, 3);
+    }
+
+    #[mz_ore::test]
+    fn test_replace_column() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // (#1 + #0) * #1 with #1 := #1 + 1 --> ((#1 + 1) + #0) * (#1 + 1)
+        let with = col(1).call_binary(lit(1), BinaryFunc::AddInt64);
+        let mut expr = col(1)
+            .call_binary(col(0), BinaryFunc::AddInt64)
+            .call_binary(col(1), BinaryFunc::MulInt64);
+        expr.replace_column(1, &with);
+        assert_eq!(
+            expr,
+            with.clone()
+                .call_binary(col(0), BinaryFunc::AddInt64)
+                .call_binary(with, BinaryFunc::MulInt64)
+        );
+
+        // Expressions without the column are left alone.
+        let mut expr = col(0).call_binary(lit(2), BinaryFunc::AddInt64);
+        let expected = expr.clone();
+        expr.replace_column(3, &lit(7));
+        assert_eq!(expr, expected);
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_like_literal_subject() {
+        let str_lit = |s| MirScalarExpr::literal_ok(Datum::String(s), ScalarType::String);
+        let column_types = vec![ScalarType::String.nullable(true)];
+
+        // 'abc' LIKE 'a%' --> true
+        let mut expr = str_lit("abc").call_binary(
+            str_lit("a%"),
+            BinaryFunc::IsLikeMatch {
+                case_insensitive: false,
+            },
+        );
+        expr.reduce(&column_types);
+        assert_eq!(expr, MirScalarExpr::literal_true());
+
+        // 'ABC' ILIKE 'a_' --> false
+        let mut expr = str_lit("ABC").call_binary(
+            str_lit("a_"),
+            BinaryFunc::IsLikeMatch {
+                case_insensitive: true,
+            },
+        );
+        expr.reduce(&column_types);
+        assert_eq!(expr, MirScalarExpr::literal_false());
+
+        // NULL LIKE 'a%' --> NULL
+        let mut expr = MirScalarExpr::literal_null(ScalarType::String).call_binary(
+            str_lit("a%"),
+            BinaryFunc::IsLikeMatch {
+                case_insensitive: false,
+            },
+        );
+        expr.reduce(&column_types);
+        assert!(expr.is_literal_null());
+
+        // A matcher precompiled against a column folds once the subject becomes a literal.
+        let mut expr = MirScalarExpr::Column(0).call_binary(
+            str_lit("a%"),
+            BinaryFunc::IsLikeMatch {
+                case_insensitive: false,
+            },
+        );
+        expr.reduce(&column_types);
+        assert!(matches!(
+            expr,
+            MirScalarExpr::CallUnary {
+                func: UnaryFunc::IsLikeMatch(_),
+                ..
+            }
+        ));
+        expr.replace_column(0, &str_lit("abc"));
+        expr.reduce(&column_types);
+        assert_eq!(expr, MirScalarExpr::literal_true());
+    }
+
+    #[mz_ore::test]
+    fn test_recognize_between() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let cmp = |e1: MirScalarExpr, func, e2| e1.call_binary(e2, func);
+
+        // #0 >= 1 AND #0 <= 5
+        let expected = Some((col(0), lit(1), lit(5)));
+        let between =
+            cmp(col(0), BinaryFunc::Gte, lit(1)).and(cmp(col(0), BinaryFunc::Lte, lit(5)));
+        assert_eq!(between.recognize_between(), expected);
+        // #0 <= 5 AND 1 <= #0
+        let between =
+            cmp(col(0), BinaryFunc::Lte, lit(5)).and(cmp(lit(1), BinaryFunc::Lte, col(0)));
+        assert_eq!(between.recognize_between(), expected);
+        // 5 >= #0 AND #0 >= 1
+        let between =
+            cmp(lit(5), BinaryFunc::Gte, col(0)).and(cmp(col(0), BinaryFunc::Gte, lit(1)));
+        assert_eq!(between.recognize_between(), expected);
+        // Bounds may be arbitrary expressions: #0 >= #1 AND #0 <= #1 + 1
+        let upper = col(1).call_binary(lit(1), BinaryFunc::AddInt64);
+        let between =
+            cmp(col(0), BinaryFunc::Gte, col(1)).and(cmp(col(0), BinaryFunc::Lte, upper.clone()));
+        assert_eq!(between.recognize_between(), Some((col(0), col(1), upper)));
+
+        // Different subjects.
+        let expr = cmp(col(0), BinaryFunc::Gte, lit(1)).and(cmp(col(1), BinaryFunc::Lte, lit(5)));
+        assert_eq!(expr.recognize_between(), None);
+        // Two lower bounds.
+        let expr = cmp(col(0), BinaryFunc::Gte, lit(1)).and(cmp(col(0), BinaryFunc::Gte, lit(5)));
+        assert_eq!(expr.recognize_between(), None);
+        // Strict comparisons.
+        let expr = cmp(col(0), BinaryFunc::Gt, lit(1)).and(cmp(col(0), BinaryFunc::Lte, lit(5)));
+        assert_eq!(expr.recognize_between(), None);
+        let expr = cmp(col(0), BinaryFunc::Gte, lit(1)).and(cmp(col(0), BinaryFunc::Lt, lit(5)));
+        assert_eq!(expr.recognize_between(), None);
+        // Not exactly two conjuncts.
+        let expr = cmp(col(0), BinaryFunc::Gte, lit(1))
+            .and(cmp(col(0), BinaryFunc::Lte, lit(5)))
+            .and(col(2));
+        assert_eq!(expr.recognize_between(), None);
+        assert_eq!(
+            cmp(col(0), BinaryFunc::Gte, lit(1)).recognize_between(),
+            None
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_is_impossible() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let column_types = vec![
+            ScalarType::Int64.nullable(true),
+            ScalarType::Int64.nullable(false),
+            ScalarType::Int16.nullable(true),
+        ];
+
+        // Literals.
+        assert!(MirScalarExpr::literal_false().is_impossible(&column_types));
+        assert!(MirScalarExpr::literal_null(ScalarType::Bool).is_impossible(&column_types));
+        assert!(!MirScalarExpr::literal_true().is_impossible(&column_types));
+
+        // 5 = 6, but not 5 = 5.
+        assert!(lit(5)
+            .call_binary(lit(6), BinaryFunc::Eq)
+            .is_impossible(&column_types));
+        assert!(!lit(5)
+            .call_binary(lit(5), BinaryFunc::Eq)
+            .is_impossible(&column_types));
+
+        // #1 IS NULL, where #1 is not nullable, but not #0 IS NULL.
+        assert!(col(1).call_is_null().is_impossible(&column_types));
+        assert!(!col(0).call_is_null().is_impossible(&column_types));
+
+        // #2::int8 = 1000000, where #2 is an int2.
+        let cast = col(2).call_unary(UnaryFunc::CastInt16ToInt64(func::CastInt16ToInt64));
+        assert!(cast
+            .clone()
+            .call_binary(lit(1000000), BinaryFunc::Eq)
+            .is_impossible(&column_types));
+        assert!(!cast
+            .call_binary(lit(1000), BinaryFunc::Eq)
+            .is_impossible(&column_types));
+
+        // #0 < 5 AND #0 > 10
+        assert!(col(0)
+            .call_binary(lit(5), BinaryFunc::Lt)
+            .and(col(0).call_binary(lit(10), BinaryFunc::Gt))
+            .is_impossible(&column_types));
+        // #0 < 5 AND 5 <= #0
+        assert!(col(0)
+            .call_binary(lit(5), BinaryFunc::Lt)
+            .and(lit(5).call_binary(col(0), BinaryFunc::Lte))
+            .is_impossible(&column_types));
+        // #0 = 3 AND #0 > 3
+        assert!(col(0)
+            .call_binary(lit(3), BinaryFunc::Eq)
+            .and(col(0).call_binary(lit(3), BinaryFunc::Gt))
+            .is_impossible(&column_types));
+        // #0 = 3 AND #0 = 4
+        assert!(col(0)
+            .call_binary(lit(3), BinaryFunc::Eq)
+            .and(col(0).call_binary(lit(4), BinaryFunc::Eq))
+            .is_impossible(&column_types));
+        // #0 <= 5 AND #0 >= 5 is satisfiable.
+        assert!(!col(0)
+            .call_binary(lit(5), BinaryFunc::Lte)
+            .and(col(0).call_binary(lit(5), BinaryFunc::Gte))
+            .is_impossible(&column_types));
+        // #0 < 5 AND #1 > 10 bounds different expressions.
+        assert!(!col(0)
+            .call_binary(lit(5), BinaryFunc::Lt)
+            .and(col(1).call_binary(lit(10), BinaryFunc::Gt))
+            .is_impossible(&column_types));
+        // (1 / #0) < 5 AND (1 / #0) > 10 errors for #0 = 0.
+        let div = lit(1).call_binary(col(0), BinaryFunc::DivInt64);
+        assert!(!div
+            .clone()
+            .call_binary(lit(5), BinaryFunc::Lt)
+            .and(div.call_binary(lit(10), BinaryFunc::Gt))
+            .is_impossible(&column_types));
+        // (#2 / #2)::int8 = 1000000 errors for #2 = 0.
+        assert!(!col(2)
+            .call_binary(col(2), BinaryFunc::DivInt16)
+            .call_unary(UnaryFunc::CastInt16ToInt64(func::CastInt16ToInt64))
+            .call_binary(lit(1000000), BinaryFunc::Eq)
+            .is_impossible(&column_types));
+        // A conjunction with an impossible conjunct.
+        assert!(col(0)
+            .call_binary(lit(5), BinaryFunc::Lt)
+            .and(MirScalarExpr::literal_false())
+            .is_impossible(&column_types));
+
+        // Disjunctions need every disjunct to be impossible.
+        assert!(col(1)
+            .call_is_null()
+            .or(lit(5).call_binary(lit(6), BinaryFunc::Eq))
+            .is_impossible(&column_types));
+        assert!(!col(1)
+            .call_is_null()
+            .or(col(0).call_is_null())
+            .is_impossible(&column_types));
+    }
+
+    #[mz_ore::test]
+    fn test_display() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        let expr = col(0).call_binary(lit(1), BinaryFunc::AddInt64);
+        assert_eq!(expr.to_string(), "(#0 + 1)");
+
+        let expr = col(2).not();
+        assert_eq!(expr.to_string(), "NOT(#2)");
+
+        let expr = col(1).call_is_null().not();
+        assert_eq!(expr.to_string(), "(#1) IS NOT NULL");
+
+        let expr = col(2).and(col(3)).if_then_else(
+            lit(1),
+            col(0).call_unary(UnaryFunc::NegInt64(func::NegInt64)),
+        );
+        assert_eq!(
+            expr.to_string(),
+            "case when (#2 AND #3) then 1 else -(#0) end"
+        );
+
+        let expr = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Coalesce,
+            exprs: vec![col(0), MirScalarExpr::literal_null(ScalarType::Int64)],
+        };
+        assert_eq!(expr.to_string(), "coalesce(#0, null)");
+    }
+
+    #[mz_ore::test]
+    fn test_column_max() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        let expr = col(3)
+            .call_binary(col(7), BinaryFunc::AddInt64)
+            .call_binary(col(1), BinaryFunc::MulInt64);
+        assert_eq!(expr.column_max(), Some(7));
+        assert_eq!(expr.column_max(), expr.support().last().copied());
+        assert_eq!(col(0).column_max(), Some(0));
+        assert_eq!(
+            lit(1)
+                .call_binary(lit(2), BinaryFunc::AddInt64)
+                .column_max(),
+            None
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_rewrite_column_indices() {
+        let col = MirScalarExpr::Column;
+
+        // A deep chain of additions, each adding a wide coalesce.
+        let depth = 1000;
+        let mut expr = col(0);
+        for i in 0..depth {
+            let wide = MirScalarExpr::CallVariadic {
+                func: VariadicFunc::Coalesce,
+                exprs: (0..10).map(|j| col(i + j)).collect(),
+            };
+            expr = expr.call_binary(wide, BinaryFunc::AddInt64);
+        }
+        let mut expected = expr.clone();
+        #[allow(deprecated)]
+        expected.visit_mut_post_nolimit(&mut |e| {
+            if let MirScalarExpr::Column(i) = e {
+                *i = 2 * *i + 1;
+            }
+        });
+
+        let mut calls = 0;
+        expr.rewrite_column_indices(|i| {
+            calls += 1;
+            *i = 2 * *i + 1;
+        });
+        assert_eq!(expr, expected);
+        assert_eq!(calls, 1 + 10 * depth);
+
+        // Literals and unmaterializable calls have no columns.
+        let mut expr = MirScalarExpr::literal_true().if_then_else(
+            MirScalarExpr::CallUnmaterializable(UnmaterializableFunc::MzNow),
+            col(3).not(),
+        );
+        expr.rewrite_column_indices(|i| *i = 7);
+        assert_eq!(
+            expr,
+            MirScalarExpr::literal_true().if_then_else(
+                MirScalarExpr::CallUnmaterializable(UnmaterializableFunc::MzNow),
+                col(7).not(),
+            )
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_single_arm_or() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let column_types = vec![ScalarType::Int64.nullable(true)];
+
+        // OR(#0 = 5), as produced by `#0 IN (5)` --> #0 = 5
+        let eq = col(0).call_binary(lit(5), BinaryFunc::Eq);
+        let mut expr = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Or,
+            exprs: vec![eq.clone()],
+        };
+        expr.reduce(&column_types);
+        let mut expected = eq.clone();
+        expected.reduce(&column_types);
+        assert_eq!(expr, expected);
+        assert!(matches!(
+            expr,
+            MirScalarExpr::CallBinary {
+                func: BinaryFunc::Eq,
+                ..
+            }
+        ));
+
+        // The same, nested in a conjunction: AND(OR(#0 = 5), #0 IS NULL) --> #0 = 5 AND #0 IS NULL
+        let mut expr = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs: vec![
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Or,
+                    exprs: vec![eq],
+                },
+                col(0).call_is_null(),
+            ],
+        };
+        expr.reduce(&column_types);
+        assert!(!matches!(
+            expr,
+            MirScalarExpr::CallVariadic {
+                func: VariadicFunc::Or,
+                ..
+            }
+        ));
+        assert_eq!(expr.conjuncts().len(), 2);
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_dominated_case_arms() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let column_types = vec![
+            ScalarType::Bool.nullable(true),
+            ScalarType::Int64.nullable(true),
+            ScalarType::Int64.nullable(true),
+            ScalarType::Int64.nullable(true),
+        ];
+        let cond = || col(0);
+
+        // CASE WHEN c THEN #1 ELSE (CASE WHEN c THEN #2 ELSE #3 END) END
+        // --> CASE WHEN c THEN #1 ELSE #3 END
+        let mut expr = cond().if_then_else(col(1), cond().if_then_else(col(2), col(3)));
+        expr.reduce(&column_types);
+        assert_eq!(expr, cond().if_then_else(col(1), col(3)));
+
+        // CASE WHEN c THEN (CASE WHEN c THEN #1 ELSE #2 END) ELSE #3 END
+        // --> CASE WHEN c THEN #1 ELSE #3 END
+        let mut expr = cond().if_then_else(cond().if_then_else(col(1), col(2)), col(3));
+        expr.reduce(&column_types);
+        assert_eq!(expr, cond().if_then_else(col(1), col(3)));
+
+        // A chain lowered from `CASE WHEN c THEN 1 WHEN c THEN 2 WHEN c THEN 3 ELSE #1 END`.
+        let mut expr = cond().if_then_else(
+            lit(1),
+            cond().if_then_else(lit(2), cond().if_then_else(lit(3), col(1))),
+        );
+        expr.reduce(&column_types);
+        assert_eq!(expr, cond().if_then_else(lit(1), col(1)));
+
+        // Different conditions are left alone.
+        let other = col(1).call_binary(lit(0), BinaryFunc::Gt);
+        let mut expr = cond().if_then_else(col(1), other.clone().if_then_else(col(2), col(3)));
+        let expected = expr.clone();
+        expr.reduce(&column_types);
+        assert_eq!(expr, expected);
+    }
+
+    #[mz_ore::test]
+    fn test_into_literal_datum() {
+        fn first_datum(e: &MirScalarExpr) -> Result<Option<Datum>, EvalError> {
+            Ok(Some(match e.into_literal_datum() {
+                Some(lit) => lit?,
+                None => return Ok(None),
+            }))
+        }
+
+        let lit = MirScalarExpr::literal_ok(Datum::Int32(7), ScalarType::Int32);
+        assert_eq!(first_datum(&lit), Ok(Some(Datum::Int32(7))));
+
+        let err = MirScalarExpr::literal(Err(EvalError::DivisionByZero), ScalarType::Int32);
+        assert_eq!(first_datum(&err), Err(EvalError::DivisionByZero));
+
+        assert_eq!(first_datum(&MirScalarExpr::Column(0)), Ok(None));
+    }
+
+    #[mz_ore::test]
+    fn test_as_is_not_null() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        let inner = col(0).call_binary(lit(1), BinaryFunc::AddInt64);
+        let expr = inner.clone().call_is_null().not();
+        assert_eq!(expr.as_is_not_null(), Some(&inner));
+
+        assert_eq!(col(0).call_is_null().as_is_not_null(), None);
+        assert_eq!(col(0).not().as_is_not_null(), None);
+        assert_eq!(col(0).call_is_null().not().not().as_is_not_null(), None);
+        assert_eq!(col(0).as_is_not_null(), None);
+
+        // `reduce` keeps the `NOT(x IS NULL)` shape.
+        let column_types = vec![ScalarType::Int64.nullable(true)];
+        let mut expr = col(0).call_is_null().not().not().not();
+        expr.reduce(&column_types);
+        assert_eq!(expr.as_is_not_null(), Some(&col(0)));
+    }
+
+    #[mz_ore::test]
+    fn test_substitute() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // (#0 + 1) * (#0 + 1) with #0 + 1 --> #1
+        let from = col(0).call_binary(lit(1), BinaryFunc::AddInt64);
+        let mut expr = from.clone().call_binary(from.clone(), BinaryFunc::MulInt64);
+        assert_eq!(expr.substitute(&from, &col(1)), 2);
+        assert_eq!(expr, col(1).call_binary(col(1), BinaryFunc::MulInt64));
+
+        // Nothing to replace.
+        assert_eq!(expr.substitute(&from, &col(1)), 0);
+        assert_eq!(expr, col(1).call_binary(col(1), BinaryFunc::MulInt64));
+
+        // The replacement may contain the replaced expression: #0 --> #0 + 1
+        let mut expr = col(0).call_binary(col(2), BinaryFunc::SubInt64);
+        assert_eq!(expr.substitute(&col(0), &from), 1);
+        assert_eq!(expr, from.clone().call_binary(col(2), BinaryFunc::SubInt64));
+    }
+
+    #[mz_ore::test]
+    fn test_depth() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        assert_eq!(col(0).depth().unwrap(), 1);
+        assert_eq!(lit(1).depth().unwrap(), 1);
+
+        // (#0 + 1) * #1
+        let expr = col(0)
+            .call_binary(lit(1), BinaryFunc::AddInt64)
+            .call_binary(col(1), BinaryFunc::MulInt64);
+        assert_eq!(expr.depth().unwrap(), 3);
+
+        // if #0 IS NULL then 1 else ((#0 + 1) * #1)
+        let expr = col(0).call_is_null().if_then_else(lit(1), expr);
+        assert_eq!(expr.depth().unwrap(), 4);
+
+        // coalesce(#0, NOT(NOT(#1 IS NULL)), 1)
+        let expr = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Coalesce,
+            exprs: vec![col(0), col(1).call_is_null().not().not(), lit(1)],
+        };
+        assert_eq!(expr.depth().unwrap(), 5);
+        assert_eq!(expr.size().unwrap(), 7);
+    }
+
+    #[mz_ore::test]
+    fn test_eval_error_sql_state() {
+        let cases = [
+            (EvalError::DivisionByZero, "22012"),
+            (EvalError::Int32OutOfRange("1".into()), "22003"),
+            (EvalError::NumericFieldOverflow, "22003"),
+            (EvalError::TimestampOutOfRange, "22008"),
+            (EvalError::DateOutOfRange, "22008"),
+            (EvalError::InvalidParameterValue("x".into()), "22023"),
+            (EvalError::NullCharacterNotPermitted, "54000"),
+            (EvalError::Internal("oops".into()), "XX000"),
+        ];
+        for (err, code) in cases {
+            assert_eq!(err.sql_state(), code, "{err}");
+            assert_eq!(err.sql_state().len(), 5);
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_eval_error_category() {
+        let cases = [
+            (EvalError::DivisionByZero, EvalErrorCategory::DivisionByZero),
+            (
+                EvalError::Int32OutOfRange("1".into()),
+                EvalErrorCategory::OutOfRange,
+            ),
+            (EvalError::FloatOverflow, EvalErrorCategory::OutOfRange),
+            (
+                EvalError::TimestampOutOfRange,
+                EvalErrorCategory::OutOfRange,
+            ),
+            (
+                EvalError::StringValueTooLong {
+                    target_type: "character varying".into(),
+                    length: 3,
+                },
+                EvalErrorCategory::OutOfRange,
+            ),
+            (EvalError::InvalidBase64Equals, EvalErrorCategory::Parse),
+            (EvalError::NegSqrt, EvalErrorCategory::Domain),
+            (
+                EvalError::ZeroOutOfDomain("log".into()),
+                EvalErrorCategory::Domain,
+            ),
+            (
+                EvalError::InvalidParameterValue("x".into()),
+                EvalErrorCategory::Domain,
+            ),
+            (
+                EvalError::Unsupported {
+                    feature: "x".into(),
+                    issue_no: None,
+                },
+                EvalErrorCategory::Unsupported,
+            ),
+            (
+                EvalError::MultiDimensionalArraySearch,
+                EvalErrorCategory::Unsupported,
+            ),
+            (
+                EvalError::Internal("oops".into()),
+                EvalErrorCategory::Internal,
+            ),
+            (
+                EvalError::MultipleRowsFromSubquery,
+                EvalErrorCategory::Other,
+            ),
+        ];
+        for (err, category) in cases {
+            assert_eq!(err.category(), category, "{err}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_extract_literals() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // (#0 + 1) * (#2 - 2) --> (#0 + #3) * (#2 - #4)
+        let mut expr = col(0)
+            .call_binary(lit(1), BinaryFunc::AddInt64)
+            .call_binary(
+                col(2).call_binary(lit(2), BinaryFunc::SubInt64),
+                BinaryFunc::MulInt64,
+            );
+        let literals = expr.extract_literals();
+        assert_eq!(
+            expr,
+            col(0)
+                .call_binary(col(3), BinaryFunc::AddInt64)
+                .call_binary(
+                    col(2).call_binary(col(4), BinaryFunc::SubInt64),
+                    BinaryFunc::MulInt64,
+                )
+        );
+        assert_eq!(
+            literals,
+            vec![
+                (
+                    Ok(Row::pack_slice(&[Datum::Int64(1)])),
+                    ScalarType::Int64.nullable(false)
+                ),
+                (
+                    Ok(Row::pack_slice(&[Datum::Int64(2)])),
+                    ScalarType::Int64.nullable(false)
+                ),
+            ]
+        );
+
+        // Without column references, the new columns start at 0.
+        let mut expr = lit(1);
+        assert_eq!(expr.extract_literals().len(), 1);
+        assert_eq!(expr, col(0));
+    }
+
+    #[mz_ore::test]
+    fn test_structural_diff() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // (#0 + 1) * #1
+        let expr = col(0)
+            .call_binary(lit(1), BinaryFunc::AddInt64)
+            .call_binary(col(1), BinaryFunc::MulInt64);
+        assert_eq!(expr.structural_diff(&expr.clone()), None);
+
+        // Differing at the root: (#0 + 1) - #1
+        let other = col(0)
+            .call_binary(lit(1), BinaryFunc::AddInt64)
+            .call_binary(col(1), BinaryFunc::SubInt64);
+        assert_eq!(expr.structural_diff(&other), Some(vec![]));
+
+        // Differing in a child: (#0 + 2) * #1
+        let other = col(0)
+            .call_binary(lit(2), BinaryFunc::AddInt64)
+            .call_binary(col(1), BinaryFunc::MulInt64);
+        assert_eq!(expr.structural_diff(&other), Some(vec![0, 1]));
+
+        // Variadic calls with different numbers of arguments differ at the call.
+        let and2 = col(0).and(col(1));
+        let and3 = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs: vec![col(0), col(1), col(2)],
+        };
+        assert_eq!(and2.structural_diff(&and3), Some(vec![]));
+    }
+
+    #[mz_ore::test]
+    fn test_soften_casts() {
+        let col = MirScalarExpr::Column;
+        let cast_to_int32 =
+            |e: MirScalarExpr| e.call_unary(UnaryFunc::CastStringToInt32(func::CastStringToInt32));
+
+        // -(text_to_integer(#0)), with a cast that has no try variant around it.
+        let mut expr = cast_to_int32(col(0))
+            .call_unary(UnaryFunc::NegInt32(func::NegInt32))
+            .call_unary(UnaryFunc::CastInt32ToInt64(func::CastInt32ToInt64));
+        expr.soften_casts();
+        let expected = col(0)
+            .call_unary(UnaryFunc::TryCastStringToInt32(func::TryCastStringToInt32))
+            .call_unary(UnaryFunc::NegInt32(func::NegInt32))
+            .call_unary(UnaryFunc::CastInt32ToInt64(func::CastInt32ToInt64));
+        assert_eq!(expr, expected);
+
+        // Invalid input now evaluates to null.
+        let mut expr = cast_to_int32(MirScalarExpr::literal_ok(
+            Datum::String("abc"),
+            ScalarType::String,
+        ));
+        let arena = RowArena::new();
+        assert!(expr.eval(&[], &arena).is_err());
+        expr.soften_casts();
+        assert_eq!(expr.eval(&[], &arena), Ok(Datum::Null));
+        assert!(expr.typ(&[]).nullable);
+    }
+
+    #[mz_ore::test]
+    fn test_subexpression_counts() {
+        let col = MirScalarExpr::Column;
+
+        // (#0 + #1) * (#0 + #1) + #0
+        let sum = col(0).call_binary(col(1), BinaryFunc::AddInt64);
+        let product = sum.clone().call_binary(sum.clone(), BinaryFunc::MulInt64);
+        let expr = product.clone().call_binary(col(0), BinaryFunc::AddInt64);
+        let expected = BTreeMap::from([(sum, 2), (product, 1), (expr.clone(), 1)]);
+        assert_eq!(expr.subexpression_counts().unwrap(), expected);
+
+        assert!(col(0).subexpression_counts().unwrap().is_empty());
+    }
+
+    #[mz_ore::test]
+    fn test_repeated_subexpr_count() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // (#0 + #1) * (#0 + #1) + #0
+        let sum = col(0).call_binary(col(1), BinaryFunc::AddInt64);
+        let expr = sum
+            .clone()
+            .call_binary(sum, BinaryFunc::MulInt64)
+            .call_binary(col(0), BinaryFunc::AddInt64);
+        assert_eq!(expr.repeated_subexpr_count(), 1);
+
+        // Repeated columns and literals don't count.
+        let expr = col(0)
+            .call_binary(lit(1), BinaryFunc::AddInt64)
+            .call_binary(
+                col(0).call_binary(lit(1), BinaryFunc::SubInt64),
+                BinaryFunc::MulInt64,
+            );
+        assert_eq!(expr.repeated_subexpr_count(), 0);
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_concat_nulls() {
+        let relation_type = vec![ScalarType::String.nullable(false)];
+        let null = || MirScalarExpr::literal_null(ScalarType::String);
+
+        // `||` propagates NULLs.
+        let mut actual = MirScalarExpr::Column(0).call_binary(null(), BinaryFunc::TextConcat);
+        actual.reduce(&relation_type);
+        assert_eq!(actual, null());
+
+        // `concat` skips NULLs.
+        let concat = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Concat,
+            exprs: vec![MirScalarExpr::Column(0), null()],
+        };
+        let mut actual = concat.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, concat);
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_abs_comparison() {
+        let relation_type = vec![
+            ScalarType::Float64.nullable(false),
+            ScalarType::Float64.nullable(true),
+            ScalarType::Int64.nullable(false),
+        ];
+        let col = MirScalarExpr::Column;
+        let zero = || MirScalarExpr::literal_ok(Datum::Float64(0.0.into()), ScalarType::Float64);
+        let abs = |e: MirScalarExpr| e.call_unary(UnaryFunc::AbsFloat64(func::AbsFloat64));
+
+        // abs(#0) >= 0 --> true
+        let mut actual = abs(col(0)).call_binary(zero(), BinaryFunc::Gte);
+        actual.reduce(&relation_type);
+        assert_eq!(actual, MirScalarExpr::literal_true());
+
+        // 0 <= abs(#1) --> NULL OR NOT(#1 IS NULL)
+        let mut actual = zero().call_binary(abs(col(1)), BinaryFunc::Lte);
+        actual.reduce(&relation_type);
+        let expected =
+            MirScalarExpr::literal_null(ScalarType::Bool).or(col(1).call_is_null().not());
+        assert_eq!(actual, expected);
+
+        // The integer `abs` can overflow, so it is left alone.
+        let expected = col(2)
+            .call_unary(UnaryFunc::AbsInt64(func::AbsInt64))
+            .call_binary(
+                MirScalarExpr::literal_ok(Datum::Int64(0), ScalarType::Int64),
+                BinaryFunc::Gte,
+            );
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_round_integral() {
+        let relation_type = vec![
+            ScalarType::Int32.nullable(true),
+            ScalarType::Numeric { max_scale: None }.nullable(true),
+        ];
+        let col = MirScalarExpr::Column;
+        let places = |n| MirScalarExpr::literal_ok(Datum::Int32(n), ScalarType::Int32);
+        let int_col = || {
+            col(0).call_unary(UnaryFunc::CastInt32ToNumeric(func::CastInt32ToNumeric(
+                None,
+            )))
+        };
+
+        // round(int_col, 0) --> int_col
+        let mut actual = int_col().call_binary(places(0), BinaryFunc::RoundNumeric);
+        actual.reduce(&relation_type);
+        assert_eq!(actual, int_col());
+
+        // round(int_col, 2) --> int_col
+        let mut actual = int_col().call_binary(places(2), BinaryFunc::RoundNumeric);
+        actual.reduce(&relation_type);
+        assert_eq!(actual, int_col());
+
+        // round(int_col) --> int_col
+        let mut actual = int_col().call_unary(UnaryFunc::RoundNumeric(func::RoundNumeric));
+        actual.reduce(&relation_type);
+        assert_eq!(actual, int_col());
+
+        // Rounding to a negative number of places changes integers.
+        let expected = int_col().call_binary(places(-1), BinaryFunc::RoundNumeric);
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+
+        // Non-integral inputs are left alone.
+        let expected = col(1).call_binary(places(0), BinaryFunc::RoundNumeric);
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_timezone_round_trip() {
+        let relation_type = vec![ScalarType::Timestamp.nullable(true)];
+        let col = MirScalarExpr::Column;
+        let round_trip = |tz: &str| {
+            let tz = func::parse_timezone(tz).unwrap();
+            col(0)
+                .call_unary(UnaryFunc::TimezoneTimestamp(func::TimezoneTimestamp(tz)))
+                .call_unary(UnaryFunc::TimezoneTimestampTz(func::TimezoneTimestampTz(
+                    tz,
+                )))
+        };
+
+        // A zero offset changes nothing, so the round trip is a no-op.
+        let mut actual = round_trip("+00:00");
+        actual.reduce(&relation_type);
+        assert_eq!(actual, col(0));
+
+        // DST makes the round trip lossy, e.g., for times skipped by a transition.
+        let expected = round_trip("America/New_York");
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+
+        // A non-zero fixed offset could push the inner conversion out of range.
+        let expected = round_trip("+05:00");
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_timezone_literal() {
+        use mz_repr::adt::interval::Interval;
+
+        let relation_type = vec![ScalarType::Timestamp.nullable(true)];
+        let col = MirScalarExpr::Column;
+        let at_time_zone = |tz: Datum, typ: ScalarType, func: BinaryFunc| {
+            MirScalarExpr::literal_ok(tz, typ).call_binary(col(0), func)
+        };
+        let interval = |days, micros| {
+            at_time_zone(
+                Datum::Interval(Interval::new(0, days, micros)),
+                ScalarType::Interval,
+                BinaryFunc::TimezoneIntervalTimestamp,
+            )
+        };
+
+        // A literal string is parsed once.
+        let mut actual = at_time_zone(
+            Datum::String("+05:00"),
+            ScalarType::String,
+            BinaryFunc::TimezoneTimestamp,
+        );
+        actual.reduce(&relation_type);
+        let tz = func::parse_timezone("+05:00").unwrap();
+        let expected = col(0).call_unary(UnaryFunc::TimezoneTimestamp(func::TimezoneTimestamp(tz)));
+        assert_eq!(actual, expected);
+
+        // A literal interval becomes the fixed offset it acts like.
+        let fixed_offset =
+            |seconds| Timezone::FixedOffset(chrono::FixedOffset::east_opt(seconds).unwrap());
+        for (days, micros, seconds) in [
+            (0, 5 * 3_600_000_000, 5 * 3600),
+            (0, -90 * 60_000_000, -90 * 60),
+            (0, 0, 0),
+        ] {
+            let mut actual = interval(days, micros);
+            actual.reduce(&relation_type);
+            let expected = col(0).call_unary(UnaryFunc::TimezoneTimestamp(
+                func::TimezoneTimestamp(fixed_offset(seconds)),
+            ));
+            assert_eq!(actual, expected);
+        }
+
+        let mut actual = at_time_zone(
+            Datum::Interval(Interval::new(0, 0, 3_600_000_000)),
+            ScalarType::Interval,
+            BinaryFunc::TimezoneIntervalTimestampTz,
+        );
+        actual.reduce(&relation_type);
+        let expected = col(0).call_unary(UnaryFunc::TimezoneTimestampTz(
+            func::TimezoneTimestampTz(fixed_offset(3600)),
+        ));
+        assert_eq!(actual, expected);
+
+        // Intervals with months are always an error.
+        let mut actual = at_time_zone(
+            Datum::Interval(Interval::new(1, 0, 0)),
+            ScalarType::Interval,
+            BinaryFunc::TimezoneIntervalTimestamp,
+        );
+        actual.reduce(&relation_type);
+        assert_eq!(
+            actual.as_literal_err(),
+            Some(&EvalError::InvalidTimezoneInterval)
+        );
+
+        // Offsets that a fixed offset can't represent are still applied per row.
+        for (days, micros) in [(1, 0), (0, 500_000)] {
+            let expected = interval(days, micros);
+            let mut actual = expected.clone();
+            actual.reduce(&relation_type);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_cast() {
+        let relation_type = vec![
+            ScalarType::Int32.nullable(true),
+            ScalarType::String.nullable(true),
+            ScalarType::VarChar {
+                max_length: Some(VarCharMaxLength::try_from(5i64).unwrap()),
+            }
+            .nullable(true),
+            ScalarType::VarChar {
+                max_length: Some(VarCharMaxLength::try_from(10i64).unwrap()),
+            }
+            .nullable(true),
+        ];
+        let col = MirScalarExpr::Column;
+        let cast = |e: MirScalarExpr, func: UnaryFunc| e.call_unary(func);
+
+        // #0::int8::int4 --> #0
+        let mut actual = cast(
+            cast(col(0), UnaryFunc::CastInt32ToInt64(func::CastInt32ToInt64)),
+            UnaryFunc::CastInt64ToInt32(func::CastInt64ToInt32),
+        );
+        actual.reduce(&relation_type);
+        assert_eq!(actual, col(0));
+
+        // #0::int2::int4 errors if #0 doesn't fit into an int2, so it is left alone.
+        let expected = cast(
+            cast(col(0), UnaryFunc::CastInt32ToInt16(func::CastInt32ToInt16)),
+            UnaryFunc::CastInt16ToInt32(func::CastInt16ToInt32),
+        );
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+
+        // A cast to the type the input already has is a no-op.
+        let to_varchar = |length: Option<u32>| {
+            UnaryFunc::CastStringToVarChar(func::CastStringToVarChar {
+                length: length.map(|l| VarCharMaxLength::try_from(i64::from(l)).unwrap()),
+                fail_on_len: false,
+            })
+        };
+        let mut actual = cast(col(2), to_varchar(Some(5)));
+        actual.reduce(&relation_type);
+        assert_eq!(actual, col(2));
+        let mut actual = cast(
+            col(1),
+            UnaryFunc::CastVarCharToString(func::CastVarCharToString),
+        );
+        actual.reduce(&relation_type);
+        assert_eq!(actual, col(1));
+
+        // Casts that change the type are kept.
+        for expected in [
+            cast(col(2), to_varchar(Some(3))),
+            cast(col(1), to_varchar(None)),
+            cast(
+                col(2),
+                UnaryFunc::CastVarCharToString(func::CastVarCharToString),
+            ),
+        ] {
+            let mut actual = expected.clone();
+            actual.reduce(&relation_type);
+            assert_eq!(actual, expected);
+        }
+
+        // #3::text::varchar(3) narrows the type and errors on values longer than 3 characters,
+        // so it is left alone.
+        let expected = cast(
+            cast(
+                col(3),
+                UnaryFunc::CastVarCharToString(func::CastVarCharToString),
+            ),
+            UnaryFunc::CastStringToVarChar(func::CastStringToVarChar {
+                length: Some(VarCharMaxLength::try_from(3i64).unwrap()),
+                fail_on_len: true,
+            }),
+        );
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+        let arena = RowArena::new();
+        assert_eq!(
+            actual.eval(
+                &[
+                    Datum::Null,
+                    Datum::Null,
+                    Datum::Null,
+                    Datum::String("abcdefgh")
+                ],
+                &arena
+            ),
+            Err(EvalError::StringValueTooLong {
+                target_type: "character varying".into(),
+                length: 3,
+            })
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_pad_leading_zero_length() {
+        let relation_type = vec![
+            ScalarType::String.nullable(false),
+            ScalarType::String.nullable(true),
+        ];
+        let col = MirScalarExpr::Column;
+        let len = |n| MirScalarExpr::literal_ok(Datum::Int64(n), ScalarType::Int64);
+        let lpad = |exprs| MirScalarExpr::CallVariadic {
+            func: VariadicFunc::PadLeading,
+            exprs,
+        };
+        let empty = MirScalarExpr::literal_ok(Datum::String(""), ScalarType::String);
+
+        // lpad(#0, 0) --> ''
+        let mut actual = lpad(vec![col(0), len(0)]);
+        actual.reduce(&relation_type);
+        assert_eq!(actual, empty);
+
+        // lpad(#0, 0, 'ab') --> ''
+        let pad = MirScalarExpr::literal_ok(Datum::String("ab"), ScalarType::String);
+        let mut actual = lpad(vec![col(0), len(0), pad]);
+        actual.reduce(&relation_type);
+        assert_eq!(actual, empty);
+
+        // A NULL #1 would make the result NULL.
+        let expected = lpad(vec![col(1), len(0)]);
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+
+        // Other lengths are left alone.
+        let expected = lpad(vec![col(0), len(3)]);
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_concat_empty_string() {
+        let relation_type = vec![
+            ScalarType::String.nullable(true),
+            ScalarType::VarChar { max_length: None }.nullable(true),
+        ];
+        let col = MirScalarExpr::Column;
+        let empty = || MirScalarExpr::literal_ok(Datum::String(""), ScalarType::String);
+        let null = || MirScalarExpr::literal_null(ScalarType::String);
+
+        // #0 || '' --> #0
+        let mut actual = col(0).call_binary(empty(), BinaryFunc::TextConcat);
+        actual.reduce(&relation_type);
+        assert_eq!(actual, col(0));
+
+        // '' || #0 --> #0
+        let mut actual = empty().call_binary(col(0), BinaryFunc::TextConcat);
+        actual.reduce(&relation_type);
+        assert_eq!(actual, col(0));
+
+        // NULL || '' --> NULL
+        let mut actual = null().call_binary(empty(), BinaryFunc::TextConcat);
+        actual.reduce(&relation_type);
+        assert_eq!(actual, null());
+
+        // Dropping the empty string would change the type of a `varchar`.
+        let expected = col(1).call_binary(empty(), BinaryFunc::TextConcat);
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_array_length() {
+        let relation_type = vec![
+            ScalarType::Int64.nullable(true),
+            ScalarType::Array(Box::new(ScalarType::Int64)).nullable(false),
+        ];
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let array = |elem_type, exprs| MirScalarExpr::CallVariadic {
+            func: VariadicFunc::ArrayCreate { elem_type },
+            exprs,
+        };
+
+        // array_length(ARRAY[#0, 2, #0], 1) --> 3
+        let mut actual = array(ScalarType::Int64, vec![col(0), lit(2), col(0)])
+            .call_binary(lit(1), BinaryFunc::ArrayLength);
+        actual.reduce(&relation_type);
+        assert_eq!(
+            actual,
+            MirScalarExpr::literal_ok(Datum::Int32(3), ScalarType::Int32)
+        );
+
+        // A one-dimensional array has no second dimension to fold.
+        let expected = array(ScalarType::Int64, vec![col(0), lit(2)])
+            .call_binary(lit(2), BinaryFunc::ArrayLength);
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+
+        // The dimensions of ARRAY[#1, #1] depend on the value of #1.
+        let expected = array(
+            ScalarType::Array(Box::new(ScalarType::Int64)),
+            vec![col(1), col(1)],
+        )
+        .call_binary(lit(1), BinaryFunc::ArrayLength);
+        let mut actual = expected.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, expected);
+    }
+
+    #[mz_ore::test]
+    fn test_is_loop_invariant() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let loop_columns = BTreeSet::from([1, 2]);
+
+        let invariant = col(0).call_binary(lit(1), BinaryFunc::AddInt64);
+        assert!(invariant.is_loop_invariant(&loop_columns));
+        assert!(lit(1).is_loop_invariant(&loop_columns));
+
+        let variant = col(0).call_binary(col(2), BinaryFunc::AddInt64);
+        assert!(!variant.is_loop_invariant(&loop_columns));
+
+        let nondeterministic = MirScalarExpr::CallUnmaterializable(UnmaterializableFunc::MzNow);
+        assert!(!nondeterministic.is_loop_invariant(&loop_columns));
+    }
+
+    #[mz_ore::test]
+    fn test_as_nullable_equality() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // #0 IS NULL OR #0 = 5
+        let expr = col(0)
+            .call_is_null()
+            .or(col(0).call_binary(lit(5), BinaryFunc::Eq));
+        assert_eq!(
+            expr.as_nullable_equality(),
+            Some((col(0), Row::pack_slice(&[Datum::Int64(5)])))
+        );
+
+        // 5 = #0 OR #0 IS NULL
+        let expr = lit(5)
+            .call_binary(col(0), BinaryFunc::Eq)
+            .or(col(0).call_is_null());
+        assert_eq!(
+            expr.as_nullable_equality(),
+            Some((col(0), Row::pack_slice(&[Datum::Int64(5)])))
+        );
+
+        // #1 IS NULL OR #0 = 5
+        let expr = col(1)
+            .call_is_null()
+            .or(col(0).call_binary(lit(5), BinaryFunc::Eq));
+        assert_eq!(expr.as_nullable_equality(), None);
+    }
+
+    #[mz_ore::test]
+    fn test_literal_bytes() {
+        let expr = MirScalarExpr::literal_bytes(vec![0xde, 0xad]);
+        assert_eq!(expr.typ(&[]), ScalarType::Bytes.nullable(false));
+        assert_eq!(expr.as_literal_bytes(), Some(&[0xde, 0xad][..]));
+        assert_eq!(expr.as_literal_str(), None);
+
+        let empty = MirScalarExpr::literal_bytes(vec![]);
+        assert_eq!(empty.as_literal_bytes(), Some(&[][..]));
+
+        let string = MirScalarExpr::literal_ok(Datum::String("dead"), ScalarType::String);
+        assert_eq!(string.as_literal_bytes(), None);
+        let null = MirScalarExpr::literal_null(ScalarType::Bytes);
+        assert_eq!(null.as_literal_bytes(), None);
+        let err = MirScalarExpr::literal(Err(EvalError::DivisionByZero), ScalarType::Bytes);
+        assert_eq!(err.as_literal_bytes(), None);
+        assert_eq!(MirScalarExpr::column(0).as_literal_bytes(), None);
+    }
+
+    #[mz_ore::test]
+    fn test_filter_characteristics_is_weak() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let fc = |filters: Vec<MirScalarExpr>| {
+            FilterCharacteristics::filter_characteristics(&filters).unwrap()
+        };
+
+        assert!(FilterCharacteristics::none().is_weak());
+        // `!=` only sets `any_filter`.
+        let ne = fc(vec![col(0).call_binary(lit(1), BinaryFunc::NotEq)]);
+        assert_eq!(ne.explain(), "f");
+        assert!(ne.is_weak());
+
+        assert!(!fc(vec![col(0).call_binary(lit(1), BinaryFunc::Eq)]).is_weak());
+        assert!(!fc(vec![col(0).call_binary(lit(1), BinaryFunc::Lt)]).is_weak());
+        assert!(!fc(vec![col(0).call_is_null()]).is_weak());
+    }
+
+    #[mz_ore::test]
+    fn test_filter_characteristics_explain() {
+        let explain = |literal_inequality| {
+            FilterCharacteristics {
+                literal_inequality,
+                any_filter: true,
+                ..FilterCharacteristics::none()
+            }
+            .explain()
+        };
+        assert_eq!(explain(0), "f");
+        assert_eq!(explain(2), "iif");
+        assert_eq!(explain(3), "iiif");
+        assert_eq!(explain(4), "i4f");
+        assert_eq!(explain(12), "i12f");
+    }
+
+    #[mz_ore::test]
+    fn test_error_literals() {
+        let col = MirScalarExpr::Column;
+        let err = |e| MirScalarExpr::literal(Err(e), ScalarType::Int64);
+
+        // if #0 = error1 then #1 else (#2 + error2)
+        let expr = col(0)
+            .call_binary(err(EvalError::DivisionByZero), BinaryFunc::Eq)
+            .if_then_else(
+                col(1),
+                col(2).call_binary(err(EvalError::FloatOverflow), BinaryFunc::AddInt64),
+            );
+        assert_eq!(
+            expr.error_literals(),
+            vec![&EvalError::DivisionByZero, &EvalError::FloatOverflow]
+        );
+        assert!(col(0).error_literals().is_empty());
+
+        assert!(expr.contains_error_literal());
+        assert!(err(EvalError::DivisionByZero).contains_error_literal());
+        assert!(!col(0).contains_error_literal());
+        // Division could error at runtime, but there is no error literal.
+        let div = col(0).call_binary(col(1), BinaryFunc::DivInt64);
+        assert!(div.could_error());
+        assert!(!div.contains_error_literal());
+    }
+
+    #[mz_ore::test]
+    fn test_reduce_literal_encoding_format() {
+        let relation_type = vec![ScalarType::Bytes.nullable(true)];
+        let format = |f| MirScalarExpr::literal_ok(Datum::String(f), ScalarType::String);
+
+        // A valid format is left for evaluation.
+        let valid = MirScalarExpr::Column(0).call_binary(format("hex"), BinaryFunc::Encode);
+        let mut actual = valid.clone();
+        actual.reduce(&relation_type);
+        assert_eq!(actual, valid);
+        assert!(actual.has_type(&relation_type, &ScalarType::String.nullable(true)));
+
+        // An invalid format is folded to an error.
+        let mut actual = MirScalarExpr::Column(0).call_binary(format("bogus"), BinaryFunc::Encode);
+        actual.reduce(&relation_type);
+        assert_eq!(
+            actual,
+            MirScalarExpr::literal(
+                Err(EvalError::InvalidEncodingName("bogus".into())),
+                ScalarType::String
+            )
+        );
+        assert!(actual.has_type(&relation_type, &ScalarType::String.nullable(false)));
+    }
+
+    #[mz_ore::test]
+    fn test_atomic_predicates() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        let a_eq_1 = col(0).call_binary(lit(1), BinaryFunc::Eq);
+        let b_lt_2 = col(1).call_binary(lit(2), BinaryFunc::Lt);
+        let c_is_null = col(2).call_is_null();
+
+        // (a = 1 OR b < 2) AND NOT (c IS NULL)
+        let expr = a_eq_1
+            .clone()
+            .or(b_lt_2.clone())
+            .and(c_is_null.clone().not());
+        assert_eq!(expr.atomic_predicates(), vec![&a_eq_1, &b_lt_2, &c_is_null]);
+
+        // A predicate that is not a logical connective is its own only atom.
+        assert_eq!(a_eq_1.atomic_predicates(), vec![&a_eq_1]);
+    }
+
+    #[mz_ore::test]
+    fn test_column_equivalence_classes() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let eq = |a, b| col(a).call_binary(col(b), BinaryFunc::Eq);
+
+        // #0 = #1 AND #1 = #2
+        let expr = eq(0, 1).and(eq(1, 2));
+        assert_eq!(
+            expr.column_equivalence_classes(),
+            vec![BTreeSet::from([0, 1, 2])]
+        );
+
+        // #3 = #0 AND #4 = #1 AND #2 = #1 AND #5 > 1
+        let expr = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs: vec![
+                eq(3, 0),
+                eq(4, 1),
+                eq(2, 1),
+                col(5).call_binary(lit(1), BinaryFunc::Gt),
+            ],
+        };
+        assert_eq!(
+            expr.column_equivalence_classes(),
+            vec![BTreeSet::from([0, 3]), BTreeSet::from([1, 2, 4])]
+        );
+
+        // Two classes that a later equality merges: #0 = #1 AND #2 = #3 AND #3 = #0
+        let expr = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs: vec![eq(0, 1), eq(2, 3), eq(3, 0)],
+        };
+        assert_eq!(
+            expr.column_equivalence_classes(),
+            vec![BTreeSet::from([0, 1, 2, 3])]
+        );
+
+        // A column equal to itself is not a class of its own.
+        assert_eq!(eq(0, 0).column_equivalence_classes(), vec![]);
+        assert_eq!(
+            col(0)
+                .call_binary(lit(1), BinaryFunc::Eq)
+                .column_equivalence_classes(),
+            vec![]
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_conjuncts_disjuncts() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        let a_eq_1 = col(0).call_binary(lit(1), BinaryFunc::Eq);
+        let b_lt_2 = col(1).call_binary(lit(2), BinaryFunc::Lt);
+        let c_is_null = col(2).call_is_null();
+
+        // (a = 1 AND b < 2) AND c IS NULL, without flattening.
+        let expr = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs: vec![a_eq_1.clone().and(b_lt_2.clone()), c_is_null.clone()],
+        };
+        assert_eq!(expr.conjuncts(), vec![&a_eq_1, &b_lt_2, &c_is_null]);
+        assert_eq!(expr.disjuncts(), vec![&expr]);
+
+        // a = 1 OR (b < 2 AND c IS NULL)
+        let inner = b_lt_2.clone().and(c_is_null.clone());
+        let expr = a_eq_1.clone().or(inner.clone());
+        assert_eq!(expr.disjuncts(), vec![&a_eq_1, &inner]);
+        assert_eq!(expr.conjuncts(), vec![&expr]);
+
+        // A predicate that is not a logical connective is its own only operand.
+        assert_eq!(a_eq_1.conjuncts(), vec![&a_eq_1]);
+        assert_eq!(a_eq_1.disjuncts(), vec![&a_eq_1]);
+    }
+
+    #[mz_ore::test]
+    fn test_into_reduced_predicates() {
+        let relation_type = vec![
+            ScalarType::Int64.nullable(true),
+            ScalarType::Int64.nullable(true),
+        ];
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        let a_eq_1 = col(0).call_binary(lit(1), BinaryFunc::Eq);
+        let b_gt_2 = col(1).call_binary(lit(2), BinaryFunc::Gt);
+
+        // a = 1 AND true AND b > 2
+        let expr = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::And,
+            exprs: vec![
+                a_eq_1.clone(),
+                MirScalarExpr::literal_true(),
+                b_gt_2.clone(),
+            ],
+        };
+        assert_eq!(
+            expr.into_reduced_predicates(&relation_type),
+            vec![a_eq_1.clone(), b_gt_2]
+        );
+
+        // A predicate that is not a conjunction is its own only conjunct.
+        assert_eq!(
+            a_eq_1.clone().into_reduced_predicates(&relation_type),
+            vec![a_eq_1.clone()]
+        );
+
+        // a = 1 OR true --> no predicates
+        let expr = a_eq_1.or(MirScalarExpr::literal_true());
+        assert_eq!(expr.into_reduced_predicates(&relation_type), vec![]);
+    }
+
+    #[mz_ore::test]
+    fn test_as_equijoin_keys() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        // #0 = #2 AND #1 = #3
+        let equijoin = col(0)
+            .call_binary(col(2), BinaryFunc::Eq)
+            .and(col(1).call_binary(col(3), BinaryFunc::Eq));
+        assert_eq!(equijoin.as_equijoin_keys(), Some(vec![(0, 2), (1, 3)]));
+
+        // A single equality is a 1-arg conjunction.
+        let single = col(0).call_binary(col(2), BinaryFunc::Eq);
+        assert_eq!(single.as_equijoin_keys(), Some(vec![(0, 2)]));
+
+        // #0 = #2 AND #1 < #3
+        let non_equality = col(0)
+            .call_binary(col(2), BinaryFunc::Eq)
+            .and(col(1).call_binary(col(3), BinaryFunc::Lt));
+        assert_eq!(non_equality.as_equijoin_keys(), None);
+
+        // #0 = #2 AND #1 = 5
+        let literal_equality = col(0)
+            .call_binary(col(2), BinaryFunc::Eq)
+            .and(col(1).call_binary(lit(5), BinaryFunc::Eq));
+        assert_eq!(literal_equality.as_equijoin_keys(), None);
+    }
+
+    #[mz_ore::test]
+    fn test_eval_checked() {
+        let arena = RowArena::new();
+
+        let shallow = MirScalarExpr::literal_true().not().not();
+        assert_eq!(shallow.eval_checked(&[], &arena), Ok(Datum::True));
+
+        let mut deep = MirScalarExpr::literal_true();
+        for _ in 0..crate::RECURSION_LIMIT * 2 {
+            deep = deep.not();
+        }
+        assert!(matches!(
+            deep.eval_checked(&[], &arena),
+            Err(EvalError::Internal(_))
+        ));
+    }
+
+    #[mz_ore::test]
+    fn test_eval_constant() {
+        let arena = RowArena::new();
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+
+        let sum = lit(1).call_binary(lit(2), BinaryFunc::AddInt64);
+        assert_eq!(sum.eval_constant(&arena), Ok(Datum::Int64(3)));
+
+        let with_column = lit(1).call_binary(MirScalarExpr::Column(0), BinaryFunc::AddInt64);
+        assert!(matches!(
+            with_column.eval_constant(&arena),
+            Err(EvalError::Internal(_))
+        ));
+
+        let now = MirScalarExpr::CallUnmaterializable(UnmaterializableFunc::MzNow);
+        assert!(matches!(
+            now.eval_constant(&arena),
+            Err(EvalError::Internal(_))
+        ));
+    }
+
+    /// Generates predicates over the columns of [`BOOLEAN_RELATION_TYPE`] that are built
+    /// from AND, OR, and NOT.
+    fn arb_boolean_expr() -> impl Strategy<Value = MirScalarExpr> {
+        let comparison = prop_oneof![
+            Just(BinaryFunc::Eq),
+            Just(BinaryFunc::NotEq),
+            Just(BinaryFunc::Lt),
+            Just(BinaryFunc::Gte),
+        ];
+        let leaf = prop_oneof![
+            (0..3_usize).prop_map(MirScalarExpr::Column),
+            (0..4_usize).prop_map(|c| MirScalarExpr::Column(c).call_is_null()),
+            (comparison, 0..3_i64).prop_map(|(func, i)| {
+                let lit = MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+                MirScalarExpr::Column(3).call_binary(lit, func)
+            }),
+            Just(MirScalarExpr::literal_true()),
+            Just(MirScalarExpr::literal_false()),
+            Just(MirScalarExpr::literal_null(ScalarType::Bool)),
+        ];
+        leaf.prop_recursive(4, 32, 4, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 1..4).prop_map(|exprs| {
+                    MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::And,
+                        exprs,
+                    }
+                }),
+                prop::collection::vec(inner.clone(), 1..4).prop_map(|exprs| {
+                    MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::Or,
+                        exprs,
+                    }
+                }),
+                inner.prop_map(|expr| expr.not()),
+            ]
+        })
+    }
+
+    /// Three boolean columns, the last of which is not nullable, and a nullable integer.
+    const BOOLEAN_RELATION_TYPE: [ColumnType; 4] = [
+        ColumnType {
+            scalar_type: ScalarType::Bool,
+            nullable: true,
+        },
+        ColumnType {
+            scalar_type: ScalarType::Bool,
+            nullable: true,
+        },
+        ColumnType {
+            scalar_type: ScalarType::Bool,
+            nullable: false,
+        },
+        ColumnType {
+            scalar_type: ScalarType::Int64,
+            nullable: true,
+        },
+    ];
+
+    proptest! {
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // too slow
+        fn reduce_is_idempotent_on_boolean_structure(expr in arb_boolean_expr()) {
+            let mut once = expr;
+            once.reduce(&BOOLEAN_RELATION_TYPE);
+            let mut twice = once.clone();
+            twice.reduce(&BOOLEAN_RELATION_TYPE);
+            assert_eq!(once, twice);
+        }
+    }
+
+    proptest! {
+        #[mz_ore::test]
+        fn mir_scalar_expr_protobuf_roundtrip(expect in any::<MirScalarExpr>()) {
+            let actual = protobuf_roundtrip::<_, ProtoMirScalarExpr>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+    }
+
+    proptest! {
+        #[mz_ore::test]
+        fn domain_limit_protobuf_roundtrip(expect in any::<DomainLimit>()) {
+            let actual = protobuf_roundtrip::<_, ProtoDomainLimit>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+    }
+
+    proptest! {
+        // Errors that Postgres reports as data exceptions are never uncategorized.
+        #[mz_ore::test]
+        fn eval_error_data_exceptions_are_categorized(err in any::<EvalError>()) {
+            if err.sql_state().starts_with("22") {
+                assert_ne!(err.category(), EvalErrorCategory::Other, "{err}");
+            }
         }
     }
 
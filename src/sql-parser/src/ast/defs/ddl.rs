@@ -1452,6 +1452,15 @@ pub enum ColumnOption<T: AstInfo> {
     },
     // `CHECK (<expr>)`
     Check(Expr<T>),
+    /// `GENERATED { ALWAYS | BY DEFAULT } AS { (<expr>) [STORED] | IDENTITY }`
+    Generated {
+        /// Whether the column was declared `ALWAYS` rather than `BY DEFAULT`.
+        always: bool,
+        /// The generation expression, or `None` for an identity column.
+        expr: Option<Expr<T>>,
+        /// Whether the generated value is `STORED`.
+        stored: bool,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for ColumnOption<T> {
@@ -1486,6 +1495,28 @@ impl<T: AstInfo> AstDisplay for ColumnOption<T> {
                 f.write_node(expr);
                 f.write_str(")");
             }
+            Generated {
+                always,
+                expr,
+                stored,
+            } => {
+                if *always {
+                    f.write_str("GENERATED ALWAYS AS ");
+                } else {
+                    f.write_str("GENERATED BY DEFAULT AS ");
+                }
+                match expr {
+                    Some(expr) => {
+                        f.write_str("(");
+                        f.write_node(expr);
+                        f.write_str(")");
+                        if *stored {
+                            f.write_str(" STORED");
+                        }
+                    }
+                    None => f.write_str("IDENTITY"),
+                }
+            }
         }
     }
 }
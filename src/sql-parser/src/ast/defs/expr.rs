@@ -88,6 +88,13 @@ pub enum Expr<T: AstInfo> {
         case_insensitive: bool,
         negated: bool,
     },
+    /// `<expr> [ NOT ] SIMILAR TO <pattern> [ ESCAPE <escape> ]`
+    SimilarTo {
+        expr: Box<Expr<T>>,
+        pattern: Box<Expr<T>>,
+        escape: Option<Box<Expr<T>>>,
+        negated: bool,
+    },
     /// `<expr> [ NOT ] BETWEEN <low> AND <high>`
     Between {
         expr: Box<Expr<T>>,
@@ -289,6 +296,23 @@ impl<T: AstInfo> AstDisplay for Expr<T> {
                     None => f.write_node(&pattern),
                 }
             }
+            Expr::SimilarTo {
+                expr,
+                pattern,
+                escape,
+                negated,
+            } => {
+                f.write_node(&expr);
+                if *negated {
+                    f.write_str(" NOT");
+                }
+                f.write_str(" SIMILAR TO ");
+                f.write_node(&pattern);
+                if let Some(escape) = escape {
+                    f.write_str(" ESCAPE ");
+                    f.write_node(escape);
+                }
+            }
             Expr::Between {
                 expr,
                 negated,
@@ -588,6 +612,7 @@ impl<T: AstInfo> Expr<T> {
         Expr::Function(Function {
             name,
             args: FunctionArgs::args(args),
+            within_group: vec![],
             filter: None,
             over: None,
             distinct: false,
@@ -687,9 +712,36 @@ impl<T: AstInfo> AstDisplay for SubscriptPosition<T> {
 }
 impl_display_t!(SubscriptPosition);
 
+/// The window in an `OVER` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum WindowType<T: AstInfo> {
+    /// An inline window specification, as in `OVER (PARTITION BY ...)`.
+    Spec(WindowSpec<T>),
+    /// A reference to a window defined in the `WINDOW` clause, as in
+    /// `OVER w`.
+    Named(Ident),
+}
+
+impl<T: AstInfo> AstDisplay for WindowType<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            WindowType::Spec(spec) => {
+                f.write_str("(");
+                f.write_node(spec);
+                f.write_str(")");
+            }
+            WindowType::Named(name) => f.write_node(name),
+        }
+    }
+}
+impl_display_t!(WindowType);
+
 /// A window specification (i.e. `OVER (PARTITION BY .. ORDER BY .. etc.)`)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct WindowSpec<T: AstInfo> {
+    /// The name of an existing window whose specification this one extends,
+    /// as in `OVER (w ORDER BY ...)`.
+    pub window_name: Option<Ident>,
     pub partition_by: Vec<Expr<T>>,
     pub order_by: Vec<OrderByExpr<T>>,
     pub window_frame: Option<WindowFrame>,
@@ -698,7 +750,12 @@ pub struct WindowSpec<T: AstInfo> {
 impl<T: AstInfo> AstDisplay for WindowSpec<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         let mut delim = "";
+        if let Some(window_name) = &self.window_name {
+            delim = " ";
+            f.write_node(window_name);
+        }
         if !self.partition_by.is_empty() {
+            f.write_str(delim);
             delim = " ";
             f.write_str("PARTITION BY ");
             f.write_node(&display::comma_separated(&self.partition_by));
@@ -797,9 +854,12 @@ impl_display!(WindowFrameBound);
 pub struct Function<T: AstInfo> {
     pub name: T::ItemName,
     pub args: FunctionArgs<T>,
+    // ordered-set aggregate functions may specify e.g.
+    // `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`
+    pub within_group: Vec<OrderByExpr<T>>,
     // aggregate functions may specify e.g. `COUNT(DISTINCT X) FILTER (WHERE ...)`
     pub filter: Option<Box<Expr<T>>>,
-    pub over: Option<WindowSpec<T>>,
+    pub over: Option<WindowType<T>>,
     // aggregate functions may specify eg `COUNT(DISTINCT x)`
     pub distinct: bool,
 }
@@ -813,15 +873,19 @@ impl<T: AstInfo> AstDisplay for Function<T> {
         }
         f.write_node(&self.args);
         f.write_str(")");
+        if !self.within_group.is_empty() {
+            f.write_str(" WITHIN GROUP (ORDER BY ");
+            f.write_node(&display::comma_separated(&self.within_group));
+            f.write_str(")");
+        }
         if let Some(filter) = &self.filter {
             f.write_str(" FILTER (WHERE ");
             f.write_node(&filter);
             f.write_str(")");
         }
         if let Some(o) = &self.over {
-            f.write_str(" OVER (");
+            f.write_str(" OVER ");
             f.write_node(o);
-            f.write_str(")");
         }
     }
 }
@@ -871,6 +935,12 @@ pub enum IsExprConstruct<T: AstInfo> {
     False,
     Unknown,
     DistinctFrom(Box<Expr<T>>),
+    /// `JSON [VALUE | OBJECT | ARRAY | SCALAR] [{WITH | WITHOUT} UNIQUE KEYS]`
+    Json {
+        ty: Option<JsonPredicateType>,
+        /// `Some(true)` for `WITH UNIQUE KEYS`, `Some(false)` for `WITHOUT UNIQUE KEYS`.
+        unique_keys: Option<bool>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for IsExprConstruct<T> {
@@ -884,7 +954,40 @@ impl<T: AstInfo> AstDisplay for IsExprConstruct<T> {
                 f.write_str("DISTINCT FROM ");
                 e.fmt(f);
             }
+            IsExprConstruct::Json { ty, unique_keys } => {
+                f.write_str("JSON");
+                if let Some(ty) = ty {
+                    f.write_str(" ");
+                    f.write_node(ty);
+                }
+                match unique_keys {
+                    Some(true) => f.write_str(" WITH UNIQUE KEYS"),
+                    Some(false) => f.write_str(" WITHOUT UNIQUE KEYS"),
+                    None => {}
+                }
+            }
         }
     }
 }
 impl_display_t!(IsExprConstruct);
+
+/// The kind of JSON value an `IS JSON` predicate tests for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum JsonPredicateType {
+    Value,
+    Object,
+    Array,
+    Scalar,
+}
+
+impl AstDisplay for JsonPredicateType {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            JsonPredicateType::Value => "VALUE",
+            JsonPredicateType::Object => "OBJECT",
+            JsonPredicateType::Array => "ARRAY",
+            JsonPredicateType::Scalar => "SCALAR",
+        })
+    }
+}
+impl_display!(JsonPredicateType);
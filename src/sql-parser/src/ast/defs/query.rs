@@ -23,7 +23,7 @@ use std::hash::Hash;
 use std::mem;
 
 use crate::ast::display::{self, AstDisplay, AstFormatter};
-use crate::ast::{AstInfo, Expr, Ident, ShowStatement, WithOptionValue};
+use crate::ast::{AstInfo, Expr, Ident, ShowStatement, WindowSpec, WithOptionValue};
 
 use super::Function;
 
@@ -226,9 +226,11 @@ pub struct Select<T: AstInfo> {
     /// WHERE
     pub selection: Option<Expr<T>>,
     /// GROUP BY
-    pub group_by: Vec<Expr<T>>,
+    pub group_by: Vec<GroupByExpr<T>>,
     /// HAVING
     pub having: Option<Expr<T>>,
+    /// WINDOW
+    pub windows: Vec<WindowDefinition<T>>,
     /// OPTION
     pub options: Vec<SelectOption<T>>,
 }
@@ -260,6 +262,10 @@ impl<T: AstInfo> AstDisplay for Select<T> {
             f.write_str(" HAVING ");
             f.write_node(having);
         }
+        if !self.windows.is_empty() {
+            f.write_str(" WINDOW ");
+            f.write_node(&display::comma_separated(&self.windows));
+        }
         if !self.options.is_empty() {
             f.write_str(" OPTIONS (");
             f.write_node(&display::comma_separated(&self.options));
@@ -286,6 +292,64 @@ impl<T: AstInfo> Select<T> {
     }
 }
 
+/// A named window definition in a `WINDOW` clause: `<name> AS (<spec>)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WindowDefinition<T: AstInfo> {
+    pub name: Ident,
+    pub spec: WindowSpec<T>,
+}
+
+impl<T: AstInfo> AstDisplay for WindowDefinition<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        f.write_str(" AS (");
+        f.write_node(&self.spec);
+        f.write_str(")");
+    }
+}
+impl_display_t!(WindowDefinition);
+
+/// An item in a `GROUP BY` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GroupByExpr<T: AstInfo> {
+    /// A plain grouping expression.
+    Expr(Expr<T>),
+    /// `GROUPING SETS (<set>, ...)`, where each set is either a single
+    /// expression or a parenthesized, possibly empty, list of expressions.
+    GroupingSets(Vec<Vec<Expr<T>>>),
+    /// `CUBE (<set>, ...)`
+    Cube(Vec<Vec<Expr<T>>>),
+    /// `ROLLUP (<set>, ...)`
+    Rollup(Vec<Vec<Expr<T>>>),
+}
+
+impl<T: AstInfo> AstDisplay for GroupByExpr<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        let (name, sets) = match self {
+            GroupByExpr::Expr(expr) => return f.write_node(expr),
+            GroupByExpr::GroupingSets(sets) => ("GROUPING SETS", sets),
+            GroupByExpr::Cube(sets) => ("CUBE", sets),
+            GroupByExpr::Rollup(sets) => ("ROLLUP", sets),
+        };
+        f.write_str(name);
+        f.write_str(" (");
+        for (i, set) in sets.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ");
+            }
+            if set.len() == 1 {
+                f.write_node(&set[0]);
+            } else {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(set));
+                f.write_str(")");
+            }
+        }
+        f.write_str(")");
+    }
+}
+impl_display_t!(GroupByExpr);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Distinct<T: AstInfo> {
     EntireRow,
@@ -529,8 +593,12 @@ impl<T: AstInfo> TableWithJoins<T> {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum TableFactor<T: AstInfo> {
     Table {
+        /// Whether the name was preceded by `ONLY`, which excludes inheriting tables.
+        only: bool,
         name: T::ItemName,
         alias: Option<TableAlias>,
+        /// The `TABLESAMPLE` clause, if any.
+        sample: Option<TableSample<T>>,
     },
     Function {
         function: Function<T>,
@@ -560,12 +628,24 @@ pub enum TableFactor<T: AstInfo> {
 impl<T: AstInfo> AstDisplay for TableFactor<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
-            TableFactor::Table { name, alias } => {
+            TableFactor::Table {
+                only,
+                name,
+                alias,
+                sample,
+            } => {
+                if *only {
+                    f.write_str("ONLY ");
+                }
                 f.write_node(name);
                 if let Some(alias) = alias {
                     f.write_str(" AS ");
                     f.write_node(alias);
                 }
+                if let Some(sample) = sample {
+                    f.write_str(" ");
+                    f.write_node(sample);
+                }
             }
             TableFactor::Function {
                 function,
@@ -627,6 +707,48 @@ impl<T: AstInfo> AstDisplay for TableFactor<T> {
 }
 impl_display_t!(TableFactor);
 
+/// A `TABLESAMPLE method (percent) [REPEATABLE (seed)]` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TableSample<T: AstInfo> {
+    pub method: TableSampleMethod,
+    /// The percentage of rows to sample, either a numeric literal or a parameter.
+    pub percent: Expr<T>,
+    /// The seed for the sampling, if `REPEATABLE` was specified.
+    pub repeatable: Option<Expr<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for TableSample<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("TABLESAMPLE ");
+        f.write_node(&self.method);
+        f.write_str(" (");
+        f.write_node(&self.percent);
+        f.write_str(")");
+        if let Some(seed) = &self.repeatable {
+            f.write_str(" REPEATABLE (");
+            f.write_node(seed);
+            f.write_str(")");
+        }
+    }
+}
+impl_display_t!(TableSample);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TableSampleMethod {
+    Bernoulli,
+    System,
+}
+
+impl AstDisplay for TableSampleMethod {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            TableSampleMethod::Bernoulli => "BERNOULLI",
+            TableSampleMethod::System => "SYSTEM",
+        })
+    }
+}
+impl_display!(TableSampleMethod);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct TableAlias {
     pub name: Ident,
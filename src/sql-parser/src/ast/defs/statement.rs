@@ -30,9 +30,9 @@ use crate::ast::display::{self, AstDisplay, AstFormatter};
 use crate::ast::{
     AstInfo, ColumnDef, CreateConnection, CreateSinkConnection, CreateSourceConnection,
     CreateSourceFormat, CreateSourceOption, CreateSourceOptionName, DeferredItemName, Envelope,
-    Expr, Format, Ident, KeyConstraint, Query, SelectItem, SourceIncludeMetadata, SubscribeOutput,
-    TableAlias, TableConstraint, TableWithJoins, UnresolvedDatabaseName, UnresolvedItemName,
-    UnresolvedObjectName, UnresolvedSchemaName, Value,
+    Expr, Format, FunctionArgs, Ident, KeyConstraint, Query, SelectItem, SourceIncludeMetadata,
+    SubscribeOutput, TableAlias, TableConstraint, TableFactor, TableWithJoins,
+    UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName, Value,
 };
 
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
@@ -45,6 +45,7 @@ pub enum Statement<T: AstInfo> {
     Copy(CopyStatement<T>),
     Update(UpdateStatement<T>),
     Delete(DeleteStatement<T>),
+    Merge(MergeStatement<T>),
     CreateConnection(CreateConnectionStatement<T>),
     CreateDatabase(CreateDatabaseStatement),
     CreateSchema(CreateSchemaStatement),
@@ -90,6 +91,10 @@ pub enum Statement<T: AstInfo> {
     Execute(ExecuteStatement<T>),
     Deallocate(DeallocateStatement),
     Raise(RaiseStatement),
+    Call(CallStatement<T>),
+    Analyze(AnalyzeStatement<T>),
+    Vacuum(VacuumStatement<T>),
+    Comment(CommentStatement),
     GrantRole(GrantRoleStatement<T>),
     RevokeRole(RevokeRoleStatement<T>),
     GrantPrivileges(GrantPrivilegesStatement<T>),
@@ -106,6 +111,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Copy(stmt) => f.write_node(stmt),
             Statement::Update(stmt) => f.write_node(stmt),
             Statement::Delete(stmt) => f.write_node(stmt),
+            Statement::Merge(stmt) => f.write_node(stmt),
             Statement::CreateConnection(stmt) => f.write_node(stmt),
             Statement::CreateDatabase(stmt) => f.write_node(stmt),
             Statement::CreateSchema(stmt) => f.write_node(stmt),
@@ -151,6 +157,10 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Execute(stmt) => f.write_node(stmt),
             Statement::Deallocate(stmt) => f.write_node(stmt),
             Statement::Raise(stmt) => f.write_node(stmt),
+            Statement::Call(stmt) => f.write_node(stmt),
+            Statement::Analyze(stmt) => f.write_node(stmt),
+            Statement::Vacuum(stmt) => f.write_node(stmt),
+            Statement::Comment(stmt) => f.write_node(stmt),
             Statement::GrantRole(stmt) => f.write_node(stmt),
             Statement::RevokeRole(stmt) => f.write_node(stmt),
             Statement::GrantPrivileges(stmt) => f.write_node(stmt),
@@ -351,7 +361,7 @@ pub struct UpdateStatement<T: AstInfo> {
     /// Column assignments
     pub assignments: Vec<Assignment<T>>,
     /// WHERE
-    pub selection: Option<Expr<T>>,
+    pub selection: Option<MutationSelection<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for UpdateStatement<T> {
@@ -377,6 +387,8 @@ impl_display_t!(UpdateStatement);
 /// `DELETE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DeleteStatement<T: AstInfo> {
+    /// `ONLY`
+    pub only: bool,
     /// `FROM`
     pub table_name: T::ItemName,
     /// `AS`
@@ -384,12 +396,15 @@ pub struct DeleteStatement<T: AstInfo> {
     /// `USING`
     pub using: Vec<TableWithJoins<T>>,
     /// `WHERE`
-    pub selection: Option<Expr<T>>,
+    pub selection: Option<MutationSelection<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for DeleteStatement<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str("DELETE FROM ");
+        if self.only {
+            f.write_str("ONLY ");
+        }
         f.write_node(&self.table_name);
         if let Some(alias) = &self.alias {
             f.write_str(" AS ");
@@ -407,6 +422,134 @@ impl<T: AstInfo> AstDisplay for DeleteStatement<T> {
 }
 impl_display_t!(DeleteStatement);
 
+/// The `WHERE` clause of an `UPDATE` or `DELETE`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MutationSelection<T: AstInfo> {
+    /// `WHERE <expr>`
+    Expr(Expr<T>),
+    /// `WHERE CURRENT OF <cursor>`
+    CurrentOf(Ident),
+}
+
+impl<T: AstInfo> AstDisplay for MutationSelection<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            MutationSelection::Expr(expr) => f.write_node(expr),
+            MutationSelection::CurrentOf(cursor) => {
+                f.write_str("CURRENT OF ");
+                f.write_node(cursor);
+            }
+        }
+    }
+}
+impl_display_t!(MutationSelection);
+
+/// `MERGE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MergeStatement<T: AstInfo> {
+    /// `INTO`
+    pub table_name: T::ItemName,
+    /// `AS`
+    pub alias: Option<TableAlias>,
+    /// `USING`
+    pub source: TableFactor<T>,
+    /// `ON`
+    pub on: Expr<T>,
+    /// `WHEN [NOT] MATCHED ...`
+    pub clauses: Vec<MergeClause<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MergeStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("MERGE INTO ");
+        f.write_node(&self.table_name);
+        if let Some(alias) = &self.alias {
+            f.write_str(" AS ");
+            f.write_node(alias);
+        }
+        f.write_str(" USING ");
+        f.write_node(&self.source);
+        f.write_str(" ON ");
+        f.write_node(&self.on);
+        for clause in &self.clauses {
+            f.write_str(" ");
+            f.write_node(clause);
+        }
+    }
+}
+impl_display_t!(MergeStatement);
+
+/// A `WHEN [NOT] MATCHED [AND <condition>] THEN <action>` clause of a `MERGE`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MergeClause<T: AstInfo> {
+    /// Whether this is a `WHEN MATCHED` (rather than a `WHEN NOT MATCHED`) clause.
+    pub matched: bool,
+    /// `AND <condition>`
+    pub condition: Option<Expr<T>>,
+    pub action: MergeAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for MergeClause<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("WHEN ");
+        if !self.matched {
+            f.write_str("NOT ");
+        }
+        f.write_str("MATCHED");
+        if let Some(condition) = &self.condition {
+            f.write_str(" AND ");
+            f.write_node(condition);
+        }
+        f.write_str(" THEN ");
+        f.write_node(&self.action);
+    }
+}
+impl_display_t!(MergeClause);
+
+/// The action taken by a [`MergeClause`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeAction<T: AstInfo> {
+    /// `UPDATE SET <assignments>`
+    Update(Vec<Assignment<T>>),
+    /// `DELETE`
+    Delete,
+    /// `INSERT [(<columns>)] {VALUES (<values>) | DEFAULT VALUES}`
+    Insert {
+        columns: Vec<Ident>,
+        /// `None` for `DEFAULT VALUES`.
+        values: Option<Vec<Expr<T>>>,
+    },
+}
+
+impl<T: AstInfo> AstDisplay for MergeAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            MergeAction::Update(assignments) => {
+                f.write_str("UPDATE SET ");
+                f.write_node(&display::comma_separated(assignments));
+            }
+            MergeAction::Delete => f.write_str("DELETE"),
+            MergeAction::Insert { columns, values } => {
+                f.write_str("INSERT");
+                if !columns.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(columns));
+                    f.write_str(")");
+                }
+                match values {
+                    Some(values) => {
+                        f.write_str(" VALUES (");
+                        f.write_node(&display::comma_separated(values));
+                        f.write_str(")");
+                    }
+                    None => f.write_str(" DEFAULT VALUES"),
+                }
+            }
+        }
+    }
+}
+impl_display_t!(MergeAction);
+
 /// `CREATE DATABASE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateDatabaseStatement {
@@ -1762,7 +1905,10 @@ impl AstDisplay for SetVariableStatement {
             f.write_str("LOCAL ");
         }
         f.write_node(&self.variable);
-        f.write_str(" = ");
+        if self.to != SetVariableTo::FromCurrent {
+            f.write_str(" =");
+        }
+        f.write_str(" ");
         f.write_node(&self.to);
     }
 }
@@ -2171,11 +2317,46 @@ impl<T: AstInfo> AstDisplay for SubscribeRelation<T> {
 }
 impl_display_t!(SubscribeRelation);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExplainOptionName {
+    Format,
+    /// The `TYPE` of plan to explain, i.e., its stage.
+    Stage,
+}
+
+impl AstDisplay for ExplainOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            ExplainOptionName::Format => "FORMAT",
+            ExplainOptionName::Stage => "TYPE",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExplainOption<T: AstInfo> {
+    pub name: ExplainOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for ExplainOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExplainStatement<T: AstInfo> {
     pub stage: ExplainStage,
     pub config_flags: Vec<Ident>,
     pub format: ExplainFormat,
+    /// The options of the parenthesized `EXPLAIN (...)` syntax, which override `stage` and
+    /// `format`. Always empty for the `EXPLAIN ... FOR` syntax.
+    pub options: Vec<ExplainOption<T>>,
     pub no_errors: bool,
     pub explainee: Explainee<T>,
 }
@@ -2183,15 +2364,21 @@ pub struct ExplainStatement<T: AstInfo> {
 impl<T: AstInfo> AstDisplay for ExplainStatement<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str("EXPLAIN ");
-        f.write_node(&self.stage);
-        if !self.config_flags.is_empty() {
-            f.write_str(" WITH(");
-            f.write_node(&display::comma_separated(&self.config_flags));
-            f.write_str(")");
+        if !self.options.is_empty() {
+            f.write_str("(");
+            f.write_node(&display::comma_separated(&self.options));
+            f.write_str(") ");
+        } else {
+            f.write_node(&self.stage);
+            if !self.config_flags.is_empty() {
+                f.write_str(" WITH(");
+                f.write_node(&display::comma_separated(&self.config_flags));
+                f.write_str(")");
+            }
+            f.write_str(" AS ");
+            f.write_node(&self.format);
+            f.write_str(" FOR ");
         }
-        f.write_str(" AS ");
-        f.write_node(&self.format);
-        f.write_str(" FOR ");
         if self.no_errors {
             f.write_str("BROKEN ");
         }
@@ -2412,6 +2599,8 @@ impl_display!(TransactionIsolationLevel);
 pub enum SetVariableTo {
     Default,
     Values(Vec<SetVariableValue>),
+    /// `SET <variable> FROM CURRENT`, which takes the place of `= <value>`.
+    FromCurrent,
 }
 
 impl AstDisplay for SetVariableTo {
@@ -2420,6 +2609,7 @@ impl AstDisplay for SetVariableTo {
         match self {
             Values(values) => f.write_node(&display::comma_separated(values)),
             Default => f.write_str("DEFAULT"),
+            FromCurrent => f.write_str("FROM CURRENT"),
         }
     }
 }
@@ -2741,6 +2931,120 @@ impl AstDisplay for RaiseStatement {
 }
 impl_display!(RaiseStatement);
 
+/// `CALL ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CallStatement<T: AstInfo> {
+    pub name: UnresolvedItemName,
+    pub args: FunctionArgs<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CallStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CALL ");
+        f.write_node(&self.name);
+        f.write_str("(");
+        f.write_node(&self.args);
+        f.write_str(")");
+    }
+}
+impl_display_t!(CallStatement);
+
+/// `ANALYZE [<table>, ...]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnalyzeStatement<T: AstInfo> {
+    /// The tables to analyze. Empty if all tables are meant.
+    pub tables: Vec<T::ItemName>,
+}
+
+impl<T: AstInfo> AstDisplay for AnalyzeStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ANALYZE");
+        if !self.tables.is_empty() {
+            f.write_str(" ");
+            f.write_node(&display::comma_separated(&self.tables));
+        }
+    }
+}
+impl_display_t!(AnalyzeStatement);
+
+/// `VACUUM [<table>, ...]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VacuumStatement<T: AstInfo> {
+    /// The tables to vacuum. Empty if all tables are meant.
+    pub tables: Vec<T::ItemName>,
+}
+
+impl<T: AstInfo> AstDisplay for VacuumStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("VACUUM");
+        if !self.tables.is_empty() {
+            f.write_str(" ");
+            f.write_node(&display::comma_separated(&self.tables));
+        }
+    }
+}
+impl_display_t!(VacuumStatement);
+
+/// `COMMENT ON <object> IS { '<text>' | NULL }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommentStatement {
+    /// The object being commented on.
+    pub object: CommentObject,
+    /// The new comment, or `None` if the comment is being removed with `IS NULL`.
+    pub comment: Option<String>,
+}
+
+impl AstDisplay for CommentStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("COMMENT ON ");
+        f.write_node(&self.object);
+        f.write_str(" IS ");
+        match &self.comment {
+            Some(comment) => {
+                f.write_str("'");
+                f.write_node(&display::escape_single_quote_string(comment));
+                f.write_str("'");
+            }
+            None => f.write_str("NULL"),
+        }
+    }
+}
+impl_display!(CommentStatement);
+
+/// The target of a `COMMENT ON` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommentObject {
+    /// A catalog object, like a table or a schema.
+    Object {
+        object_type: ObjectType,
+        name: UnresolvedObjectName,
+    },
+    /// A column of a table-like item.
+    Column {
+        relation: UnresolvedItemName,
+        column: Ident,
+    },
+}
+
+impl AstDisplay for CommentObject {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            CommentObject::Object { object_type, name } => {
+                f.write_node(object_type);
+                f.write_str(" ");
+                f.write_node(name);
+            }
+            CommentObject::Column { relation, column } => {
+                f.write_str("COLUMN ");
+                f.write_node(relation);
+                f.write_str(".");
+                f.write_node(column);
+            }
+        }
+    }
+}
+impl_display!(CommentObject);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NoticeSeverity {
     Debug,
@@ -26,7 +26,7 @@
 //! by invoking the right folder method on each of its fields.
 //!
 //! ```
-//! # use mz_sql_parser::ast::{Expr, Function, FunctionArgs, WindowSpec, Raw, AstInfo};
+//! # use mz_sql_parser::ast::{Expr, Function, FunctionArgs, OrderByExpr, WindowType, Raw, AstInfo};
 //! #
 //! pub trait Fold<T: AstInfo, T2: AstInfo> {
 //!     /* ... */
@@ -39,7 +39,8 @@
 //!     # fn fold_item_name(&mut self, node: <T as AstInfo>::ItemName) -> <T2 as AstInfo>::ItemName;
 //!     # fn fold_function_args(&mut self, node: FunctionArgs<T>) -> FunctionArgs<T2>;
 //!     # fn fold_expr(&mut self, node: Expr<T>) -> Expr<T2>;
-//!     # fn fold_window_spec(&mut self, node: WindowSpec<T>) -> WindowSpec<T2>;
+//!     # fn fold_order_by_expr(&mut self, node: OrderByExpr<T>) -> OrderByExpr<T2>;
+//!     # fn fold_window_type(&mut self, node: WindowType<T>) -> WindowType<T2>;
 //! }
 //!
 //! pub fn fold_function<F, T: AstInfo, T2: AstInfo>(folder: &mut F, node: Function<T>) -> Function<T2>
@@ -49,8 +50,9 @@
 //!     Function {
 //!         name: folder.fold_item_name(node.name),
 //!         args: folder.fold_function_args(node.args),
+//!         within_group: node.within_group.into_iter().map(|obe| folder.fold_order_by_expr(obe)).collect(),
 //!         filter: node.filter.map(|filter| Box::new(folder.fold_expr(*filter))),
-//!         over: node.over.map(|over| folder.fold_window_spec(over)),
+//!         over: node.over.map(|over| folder.fold_window_type(over)),
 //!         distinct: node.distinct,
 //!    }
 //! }
@@ -26,7 +26,7 @@
 //! by invoking the right visitor method of each of its fields.
 //!
 //! ```
-//! # use mz_sql_parser::ast::{Expr, Function, FunctionArgs, WindowSpec, Raw, AstInfo};
+//! # use mz_sql_parser::ast::{Expr, Function, FunctionArgs, WindowType, Raw, AstInfo};
 //! #
 //! pub trait Visit<'ast, T: AstInfo> {
 //!     /* ... */
@@ -39,7 +39,7 @@
 //!     # fn visit_item_name(&mut self, node: &'ast <T as AstInfo>::ItemName);
 //!     # fn visit_function_args(&mut self, node: &'ast FunctionArgs<T>);
 //!     # fn visit_expr(&mut self, node: &'ast Expr<T>);
-//!     # fn visit_window_spec(&mut self, node: &'ast WindowSpec<T>);
+//!     # fn visit_window_type(&mut self, node: &'ast WindowType<T>);
 //! }
 //!
 //! pub fn visit_function<'ast, V, T: AstInfo>(visitor: &mut V, node: &'ast Function<T>)
@@ -52,7 +52,7 @@
 //!         visitor.visit_expr(&*filter);
 //!     }
 //!     if let Some(over) = &node.over {
-//!         visitor.visit_window_spec(over);
+//!         visitor.visit_window_type(over);
 //!     }
 //! }
 //! ```
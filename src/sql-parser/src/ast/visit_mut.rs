@@ -25,7 +25,7 @@
 //! invoking the right visitor method of each of its fields.
 //!
 //! ```
-//! # use mz_sql_parser::ast::{Expr, Function, FunctionArgs, WindowSpec, AstInfo};
+//! # use mz_sql_parser::ast::{Expr, Function, FunctionArgs, WindowType, AstInfo};
 //! #
 //! pub trait VisitMut<'ast, T: AstInfo> {
 //!     /* ... */
@@ -38,7 +38,7 @@
 //!     # fn visit_item_name_mut(&mut self, node: &'ast mut <T as AstInfo>::ItemName);
 //!     # fn visit_function_args_mut(&mut self, node: &'ast mut FunctionArgs<T>);
 //!     # fn visit_expr_mut(&mut self, node: &'ast mut Expr<T>);
-//!     # fn visit_window_spec_mut(&mut self, node: &'ast mut WindowSpec<T>);
+//!     # fn visit_window_type_mut(&mut self, node: &'ast mut WindowType<T>);
 //! }
 //!
 //! pub fn visit_function_mut<'ast, V, T: AstInfo>(visitor: &mut V, node: &'ast mut Function<T>)
@@ -51,7 +51,7 @@
 //!         visitor.visit_expr_mut(&mut *filter);
 //!     }
 //!     if let Some(over) = &mut node.over {
-//!         visitor.visit_window_spec_mut(over);
+//!         visitor.visit_window_type_mut(over);
 //!     }
 //! }
 //! ```
@@ -93,6 +93,11 @@ impl Keyword {
             ON | JOIN | INNER | CROSS | FULL | LEFT | RIGHT | NATURAL | USING |
             // Needed for UPDATE.
             SET |
+            // Needed for the `WINDOW` clause, which may directly follow the
+            // `FROM` clause.
+            WINDOW |
+            // Needed for `TABLESAMPLE`, which follows the table alias.
+            TABLESAMPLE |
             // `OUTER` is not strictly ambiguous, but it prevents `a OUTER JOIN
             // b` from parsing as `a AS outer JOIN b`, instead producing a nice
             // syntax error.
@@ -116,7 +121,9 @@ impl Keyword {
             // suffixes. They are not strictly ambiguous, but marking them
             // reserved prevents e.g. `SELECT pg_catalog.interval '1' year` from
             // parsing as `SELECT pg_catalog.interval '1' AS YEAR`.
-            YEAR | MONTH | DAY | HOUR | MINUTE | SECOND
+            YEAR | MONTH | DAY | HOUR | MINUTE | SECOND |
+            // Needed for the `WINDOW` clause in a `SELECT` without `FROM`.
+            WINDOW
         ) || self.is_reserved()
     }
 
@@ -282,8 +282,17 @@ fn lex_to_adjacent_string(buf: &mut LexBuf) -> bool {
 
 fn lex_dollar_string(buf: &mut LexBuf) -> Result<Token, ParserError> {
     let pos = buf.pos() - 1;
-    let tag = format!("${}$", buf.take_while(|ch| ch != '$'));
-    let _ = buf.next();
+    // The tag follows the same rules as an unquoted identifier, except that it
+    // may not contain `$` and is case sensitive. It may be empty.
+    let tag = buf.take_while(
+        |ch| matches!(ch, 'A'..='Z' | 'a'..='z' | '0'..='9' | '_' | '\u{80}'..=char::MAX),
+    );
+    if buf.peek().is_none() {
+        bail!(pos, "unterminated dollar-quoted string");
+    } else if !buf.consume('$') {
+        bail!(pos, "invalid dollar-quoted string tag");
+    }
+    let tag = format!("${}$", tag);
     if let Some(s) = buf.take_to_delimiter(&tag) {
         Ok(Token::String(s.into()))
     } else {
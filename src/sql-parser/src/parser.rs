@@ -157,6 +157,9 @@ pub struct ParserError {
     pub message: String,
     /// The byte position with which the error is associated.
     pub pos: usize,
+    /// The byte position at which the offending token ends, if known, so that the error
+    /// spans `pos..end`.
+    pub end: Option<usize>,
 }
 
 impl fmt::Display for ParserError {
@@ -171,6 +174,7 @@ impl From<RecursionLimitError> for ParserError {
     fn from(_: RecursionLimitError) -> ParserError {
         ParserError {
             pos: 0,
+            end: None,
             message: format!(
                 "statement exceeds nested expression limit of {}",
                 RECURSION_LIMIT
@@ -187,6 +191,7 @@ impl ParserError {
     {
         ParserError {
             pos,
+            end: None,
             message: message.into(),
         }
     }
@@ -240,7 +245,22 @@ impl<'a> Parser<'a> {
     }
 
     fn error(&self, pos: usize, message: String) -> ParserError {
-        ParserError { pos, message }
+        ParserError {
+            pos,
+            end: self.token_end(pos),
+            message,
+        }
+    }
+
+    /// Returns the byte position at which the token starting at `pos` ends, or `None` if no
+    /// token starts at `pos`.
+    ///
+    /// Tokens only record where they start, so this is the start of the following token (or
+    /// the end of the input) with any whitespace in between trimmed off.
+    fn token_end(&self, pos: usize) -> Option<usize> {
+        let i = self.tokens.binary_search_by_key(&pos, |(_, p)| *p).ok()?;
+        let next = self.tokens.get(i + 1).map_or(self.sql.len(), |(_, p)| *p);
+        Some(pos + self.sql[pos..next].trim_end().len())
     }
 
     fn parse_statements(&mut self) -> Result<Vec<Statement<Raw>>, ParserError> {
@@ -270,7 +290,10 @@ impl<'a> Parser<'a> {
     fn parse_statement(&mut self) -> Result<Statement<Raw>, ParserError> {
         match self.next_token() {
             Some(t) => match t {
-                Token::Keyword(SELECT) | Token::Keyword(WITH) | Token::Keyword(VALUES) => {
+                Token::Keyword(SELECT)
+                | Token::Keyword(WITH)
+                | Token::Keyword(VALUES)
+                | Token::Keyword(TABLE) => {
                     self.prev_token();
                     Ok(Statement::Select(SelectStatement {
                         query: self.parse_query()?,
@@ -283,6 +306,7 @@ impl<'a> Parser<'a> {
                 Token::Keyword(DELETE) => Ok(self.parse_delete()?),
                 Token::Keyword(INSERT) => Ok(self.parse_insert()?),
                 Token::Keyword(UPDATE) => Ok(self.parse_update()?),
+                Token::Keyword(MERGE) => Ok(self.parse_merge()?),
                 Token::Keyword(ALTER) => Ok(self.parse_alter()?),
                 Token::Keyword(COPY) => Ok(self.parse_copy()?),
                 Token::Keyword(SET) => Ok(self.parse_set()?),
@@ -305,6 +329,10 @@ impl<'a> Parser<'a> {
                 Token::Keyword(EXECUTE) => Ok(self.parse_execute()?),
                 Token::Keyword(DEALLOCATE) => Ok(self.parse_deallocate()?),
                 Token::Keyword(RAISE) => Ok(self.parse_raise()?),
+                Token::Keyword(CALL) => Ok(self.parse_call()?),
+                Token::Keyword(ANALYZE) => Ok(self.parse_analyze()?),
+                Token::Keyword(VACUUM) => Ok(self.parse_vacuum()?),
+                Token::Keyword(COMMENT) => Ok(self.parse_comment()?),
                 Token::Keyword(GRANT) => Ok(self.parse_grant()?),
                 Token::Keyword(REVOKE) => Ok(self.parse_revoke()?),
                 Token::Keyword(REASSIGN) => Ok(self.parse_reassign_owned()?),
@@ -337,14 +365,18 @@ impl<'a> Parser<'a> {
 
     /// Parse tokens until the precedence decreases
     fn parse_subexpr(&mut self, precedence: Precedence) -> Result<Expr<Raw>, ParserError> {
+        let start = self.peek_pos();
         let expr = self.checked_recur_mut(|parser| parser.parse_prefix())?;
-        self.parse_subexpr_seeded(precedence, expr)
+        self.parse_subexpr_seeded(precedence, expr, start)
     }
 
+    /// Parse tokens until the precedence decreases, where `expr` is an
+    /// already-parsed prefix that starts at position `start`.
     fn parse_subexpr_seeded(
         &mut self,
         precedence: Precedence,
         mut expr: Expr<Raw>,
+        start: usize,
     ) -> Result<Expr<Raw>, ParserError> {
         self.checked_recur_mut(|parser| {
             loop {
@@ -353,7 +385,7 @@ impl<'a> Parser<'a> {
                     break;
                 }
 
-                expr = parser.parse_infix(expr, next_precedence)?;
+                expr = parser.parse_infix(expr, next_precedence, start)?;
             }
             Ok(expr)
         })
@@ -539,6 +571,7 @@ impl<'a> Parser<'a> {
         // includes the opening/closing parentheses, even though this function
         // is not responsible for parsing them.
         fn parse(parser: &mut Parser) -> Result<Either, ParserError> {
+            let start = parser.peek_pos();
             if parser.peek_keyword(SELECT) || parser.peek_keyword(WITH) {
                 // Easy case one: unambiguously a subquery.
                 Ok(Either::Query(parser.parse_query()?))
@@ -595,7 +628,7 @@ impl<'a> Parser<'a> {
                     // prefix.
                     (either, _) => {
                         let prefix = either.into_expr();
-                        let expr = parser.parse_subexpr_seeded(Precedence::Zero, prefix)?;
+                        let expr = parser.parse_subexpr_seeded(Precedence::Zero, prefix, start)?;
                         Ok(Either::Expr(expr).nest())
                     }
                 }
@@ -620,6 +653,23 @@ impl<'a> Parser<'a> {
             ));
         }
 
+        let within_group = if self.peek_keywords(&[WITHIN, GROUP]) {
+            if distinct {
+                return parser_err!(
+                    self,
+                    self.peek_pos(),
+                    "WITHIN GROUP not supported with DISTINCT"
+                );
+            }
+            self.expect_keywords(&[WITHIN, GROUP])?;
+            self.expect_token(&Token::LParen)?;
+            self.expect_keywords(&[ORDER, BY])?;
+            let order_by = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            self.expect_token(&Token::RParen)?;
+            order_by
+        } else {
+            vec![]
+        };
         let filter = if self.parse_keyword(FILTER) {
             self.expect_token(&Token::LParen)?;
             self.expect_keyword(WHERE)?;
@@ -630,32 +680,13 @@ impl<'a> Parser<'a> {
             None
         };
         let over = if self.parse_keyword(OVER) {
-            // TBD: support window names (`OVER mywin`) in place of inline specification
-            self.expect_token(&Token::LParen)?;
-            let partition_by = if self.parse_keywords(&[PARTITION, BY]) {
-                // a list of possibly-qualified column names
-                self.parse_comma_separated(Parser::parse_expr)?
-            } else {
-                vec![]
-            };
-            let order_by = if self.parse_keywords(&[ORDER, BY]) {
-                self.parse_comma_separated(Parser::parse_order_by_expr)?
-            } else {
-                vec![]
-            };
-            let window_frame = if !self.consume_token(&Token::RParen) {
-                let window_frame = self.parse_window_frame()?;
+            if self.consume_token(&Token::LParen) {
+                let spec = self.parse_window_spec()?;
                 self.expect_token(&Token::RParen)?;
-                Some(window_frame)
+                Some(WindowType::Spec(spec))
             } else {
-                None
-            };
-
-            Some(WindowSpec {
-                partition_by,
-                order_by,
-                window_frame,
-            })
+                Some(WindowType::Named(self.parse_identifier()?))
+            }
         } else {
             None
         };
@@ -663,12 +694,59 @@ impl<'a> Parser<'a> {
         Ok(Function {
             name,
             args,
+            within_group,
             filter,
             over,
             distinct,
         })
     }
 
+    /// Parses the body of a window specification, up to but not including the
+    /// closing parenthesis.
+    fn parse_window_spec(&mut self) -> Result<WindowSpec<Raw>, ParserError> {
+        // An optional existing window name, as in `OVER (w ORDER BY x)`.
+        let window_name = match self.peek_token() {
+            Some(Token::Ident(_)) => Some(self.parse_identifier()?),
+            Some(Token::Keyword(kw))
+                if !matches!(kw, PARTITION | ORDER | ROWS | RANGE | GROUPS) =>
+            {
+                Some(self.parse_identifier()?)
+            }
+            _ => None,
+        };
+        let partition_by = if self.parse_keywords(&[PARTITION, BY]) {
+            // a list of possibly-qualified column names
+            self.parse_comma_separated(Parser::parse_expr)?
+        } else {
+            vec![]
+        };
+        let order_by = if self.parse_keywords(&[ORDER, BY]) {
+            self.parse_comma_separated(Parser::parse_order_by_expr)?
+        } else {
+            vec![]
+        };
+        let window_frame = if self.peek_token() != Some(Token::RParen) {
+            Some(self.parse_window_frame()?)
+        } else {
+            None
+        };
+        Ok(WindowSpec {
+            window_name,
+            partition_by,
+            order_by,
+            window_frame,
+        })
+    }
+
+    fn parse_window_definition(&mut self) -> Result<WindowDefinition<Raw>, ParserError> {
+        let name = self.parse_identifier()?;
+        self.expect_keyword(AS)?;
+        self.expect_token(&Token::LParen)?;
+        let spec = self.parse_window_spec()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(WindowDefinition { name, spec })
+    }
+
     fn parse_window_frame(&mut self) -> Result<WindowFrame, ParserError> {
         let units = match self.expect_one_of_keywords(&[ROWS, RANGE, GROUPS])? {
             ROWS => WindowFrameUnits::Rows,
@@ -795,6 +873,7 @@ impl<'a> Parser<'a> {
         Ok(Expr::Function(Function {
             name: RawItemName::Name(UnresolvedItemName::unqualified("extract")),
             args: FunctionArgs::args(vec![Expr::Value(Value::String(field)), expr]),
+            within_group: vec![],
             filter: None,
             over: None,
             distinct: false,
@@ -858,6 +937,7 @@ impl<'a> Parser<'a> {
         Ok(Expr::Function(Function {
             name: RawItemName::Name(UnresolvedItemName::unqualified(name)),
             args: FunctionArgs::args(exprs),
+            within_group: vec![],
             filter: None,
             over: None,
             distinct: false,
@@ -876,6 +956,7 @@ impl<'a> Parser<'a> {
         Ok(Expr::Function(Function {
             name: RawItemName::Name(UnresolvedItemName::unqualified("position")),
             args: FunctionArgs::args(vec![needle, haystack]),
+            within_group: vec![],
             filter: None,
             over: None,
             distinct: false,
@@ -970,11 +1051,13 @@ impl<'a> Parser<'a> {
         }))
     }
 
-    /// Parse an operator following an expression
+    /// Parse an operator following `expr`, which starts at position `start`,
+    /// and the operator's right-hand side, if any.
     fn parse_infix(
         &mut self,
         expr: Expr<Raw>,
         precedence: Precedence,
+        start: usize,
     ) -> Result<Expr<Raw>, ParserError> {
         let tok = self.next_token().unwrap(); // safe as EOF's precedence is the lowest
 
@@ -1044,7 +1127,7 @@ impl<'a> Parser<'a> {
                 IS => {
                     let negated = self.parse_keyword(NOT);
                     if let Some(construct) =
-                        self.parse_one_of_keywords(&[NULL, TRUE, FALSE, UNKNOWN, DISTINCT])
+                        self.parse_one_of_keywords(&[NULL, TRUE, FALSE, UNKNOWN, DISTINCT, JSON])
                     {
                         Ok(Expr::IsExpr {
                             expr: Box::new(expr),
@@ -1059,13 +1142,14 @@ impl<'a> Parser<'a> {
                                     let expr = self.parse_expr()?;
                                     IsExprConstruct::DistinctFrom(Box::new(expr))
                                 }
+                                JSON => self.parse_is_json_construct()?,
                                 _ => unreachable!(),
                             },
                         })
                     } else {
                         self.expected(
                             self.peek_pos(),
-                            "NULL, NOT NULL, TRUE, NOT TRUE, FALSE, NOT FALSE, UNKNOWN, NOT UNKNOWN after IS",
+                            "NULL, NOT NULL, TRUE, NOT TRUE, FALSE, NOT FALSE, UNKNOWN, NOT UNKNOWN, JSON, NOT JSON after IS",
                             self.peek_token(),
                         )
                     }
@@ -1075,7 +1159,7 @@ impl<'a> Parser<'a> {
                     negated: false,
                     construct: IsExprConstruct::Null,
                 }),
-                NOT | IN | LIKE | ILIKE | BETWEEN => {
+                NOT | IN | LIKE | ILIKE | SIMILAR | BETWEEN => {
                     self.prev_token();
                     let negated = self.parse_keyword(NOT);
                     if self.parse_keyword(IN) {
@@ -1086,10 +1170,13 @@ impl<'a> Parser<'a> {
                         self.parse_like(expr, false, negated)
                     } else if self.parse_keyword(ILIKE) {
                         self.parse_like(expr, true, negated)
+                    } else if self.parse_keyword(SIMILAR) {
+                        self.expect_keyword(TO)?;
+                        self.parse_similar_to(expr, negated)
                     } else {
                         self.expected(
                             self.peek_pos(),
-                            "IN, BETWEEN, LIKE, or ILIKE after NOT",
+                            "IN, BETWEEN, LIKE, ILIKE, or SIMILAR TO after NOT",
                             self.peek_token(),
                         )
                     }
@@ -1107,6 +1194,7 @@ impl<'a> Parser<'a> {
                     Ok(Expr::Function(Function {
                         name: RawItemName::Name(UnresolvedItemName::unqualified("timezone")),
                         args: FunctionArgs::args(vec![self.parse_subexpr(precedence)?, expr]),
+                        within_group: vec![],
                         filter: None,
                         over: None,
                         distinct: false,
@@ -1116,6 +1204,7 @@ impl<'a> Parser<'a> {
                     expr: Box::new(expr),
                     collation: self.parse_item_name()?,
                 }),
+                OVERLAPS => self.parse_overlaps(expr, start, precedence),
                 // Can only happen if `get_next_precedence` got out of sync with this function
                 _ => panic!("No infix parser for token {:?}", tok),
             }
@@ -1149,15 +1238,73 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the right-hand side of `(start1, end1) OVERLAPS (start2, end2)`,
+    /// assuming the `OVERLAPS` keyword has already been consumed.
+    ///
+    /// The predicate is represented as a call to the `overlaps` function with
+    /// the four period endpoints as arguments.
+    fn parse_overlaps(
+        &mut self,
+        left: Expr<Raw>,
+        left_pos: usize,
+        precedence: Precedence,
+    ) -> Result<Expr<Raw>, ParserError> {
+        let mut args = self.overlaps_period(left, left_pos, "left")?;
+        let right_pos = self.peek_pos();
+        let right = self.parse_subexpr(precedence)?;
+        args.extend(self.overlaps_period(right, right_pos, "right")?);
+        Ok(Expr::Function(Function {
+            name: RawItemName::Name(UnresolvedItemName::unqualified("overlaps")),
+            args: FunctionArgs::args(args),
+            within_group: vec![],
+            filter: None,
+            over: None,
+            distinct: false,
+        }))
+    }
+
+    /// Extracts the two endpoints of one side of an `OVERLAPS` predicate.
+    fn overlaps_period(
+        &self,
+        expr: Expr<Raw>,
+        pos: usize,
+        side: &str,
+    ) -> Result<Vec<Expr<Raw>>, ParserError> {
+        match expr {
+            Expr::Row { exprs } if exprs.len() == 2 => Ok(exprs),
+            Expr::Row { exprs } => parser_err!(
+                self,
+                pos,
+                "{} side of OVERLAPS must have exactly two elements, found {}",
+                side,
+                exprs.len()
+            ),
+            _ => parser_err!(
+                self,
+                pos,
+                "{} side of OVERLAPS must be a row with exactly two elements",
+                side
+            ),
+        }
+    }
+
     /// Parse subscript expression, i.e. either an index value or slice range.
     fn parse_subscript(&mut self, expr: Expr<Raw>) -> Result<Expr<Raw>, ParserError> {
         let mut positions = Vec::new();
 
         while self.consume_token(&Token::LBracket) {
-            let start = if self.peek_token() == Some(Token::Colon) {
-                None
-            } else {
-                Some(self.parse_expr()?)
+            let start = match self.peek_token() {
+                // `a[:]` is not empty: as in PostgreSQL, it's a slice without
+                // bounds, i.e., the full range of the dimension.
+                Some(Token::Colon) => None,
+                Some(Token::RBracket) => {
+                    return parser_err!(
+                        self,
+                        self.peek_pos(),
+                        "expected an index or a slice between brackets"
+                    );
+                }
+                _ => Some(self.parse_expr()?),
             };
 
             let (end, explicit_slice) = if self.consume_token(&Token::Colon) {
@@ -1175,16 +1322,6 @@ impl<'a> Parser<'a> {
                 (None, false)
             };
 
-            assert!(
-                start.is_some() || explicit_slice,
-                "user typed something between brackets"
-            );
-
-            assert!(
-                explicit_slice || end.is_none(),
-                "if end is some, must have an explicit slice"
-            );
-
             positions.push(SubscriptPosition {
                 start,
                 end,
@@ -1231,6 +1368,7 @@ impl<'a> Parser<'a> {
         Ok(Expr::Function(Function {
             name: RawItemName::Name(UnresolvedItemName::unqualified("substring")),
             args: FunctionArgs::args(exprs),
+            within_group: vec![],
             filter: None,
             over: None,
             distinct: false,
@@ -1260,6 +1398,30 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the remainder of an `IS [NOT] JSON` predicate, assuming the `JSON` keyword was
+    /// already consumed
+    fn parse_is_json_construct(&mut self) -> Result<IsExprConstruct<Raw>, ParserError> {
+        let ty = match self.parse_one_of_keywords(&[VALUE, OBJECT, ARRAY, SCALAR]) {
+            Some(VALUE) => Some(JsonPredicateType::Value),
+            Some(OBJECT) => Some(JsonPredicateType::Object),
+            Some(ARRAY) => Some(JsonPredicateType::Array),
+            Some(SCALAR) => Some(JsonPredicateType::Scalar),
+            Some(_) => unreachable!(),
+            None => None,
+        };
+        let unique_keys = if self.parse_keywords(&[WITH, UNIQUE]) {
+            Some(true)
+        } else if self.parse_keywords(&[WITHOUT, UNIQUE]) {
+            Some(false)
+        } else {
+            None
+        };
+        if unique_keys.is_some() {
+            let _ = self.parse_keyword(KEYS);
+        }
+        Ok(IsExprConstruct::Json { ty, unique_keys })
+    }
+
     /// Parses the parens following the `[ NOT ] IN` operator
     fn parse_in(&mut self, expr: Expr<Raw>, negated: bool) -> Result<Expr<Raw>, ParserError> {
         self.expect_token(&Token::LParen)?;
@@ -1321,6 +1483,27 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses `SIMILAR TO <pattern> [ ESCAPE <char> ]`, assuming the `SIMILAR TO` keywords were
+    /// already consumed
+    fn parse_similar_to(
+        &mut self,
+        expr: Expr<Raw>,
+        negated: bool,
+    ) -> Result<Expr<Raw>, ParserError> {
+        let pattern = self.parse_subexpr(Precedence::Like)?;
+        let escape = if self.parse_keyword(ESCAPE) {
+            Some(Box::new(self.parse_subexpr(Precedence::Like)?))
+        } else {
+            None
+        };
+        Ok(Expr::SimilarTo {
+            expr: Box::new(expr),
+            pattern: Box::new(pattern),
+            escape,
+            negated,
+        })
+    }
+
     /// Parse a postgresql casting style which is in the form of `expr::datatype`
     fn parse_pg_cast(&mut self, expr: Expr<Raw>) -> Result<Expr<Raw>, ParserError> {
         Ok(Expr::Cast {
@@ -1345,6 +1528,7 @@ impl<'a> Parser<'a> {
                     Some(Token::Keyword(BETWEEN)) => Precedence::Like,
                     Some(Token::Keyword(ILIKE)) => Precedence::Like,
                     Some(Token::Keyword(LIKE)) => Precedence::Like,
+                    Some(Token::Keyword(SIMILAR)) => Precedence::Like,
                     _ => Precedence::Zero,
                 },
                 Token::Keyword(IS) | Token::Keyword(ISNULL) => Precedence::Is,
@@ -1352,7 +1536,14 @@ impl<'a> Parser<'a> {
                 Token::Keyword(BETWEEN) => Precedence::Like,
                 Token::Keyword(ILIKE) => Precedence::Like,
                 Token::Keyword(LIKE) => Precedence::Like,
+                Token::Keyword(SIMILAR) => Precedence::Like,
                 Token::Keyword(OPERATOR) => Precedence::Other,
+                // `OVERLAPS` is not reserved, so it is only an operator when
+                // it is followed by a row.
+                Token::Keyword(OVERLAPS) => match &self.peek_nth_token(1) {
+                    Some(Token::LParen) | Some(Token::Keyword(ROW)) => Precedence::Cmp,
+                    _ => Precedence::Zero,
+                },
                 Token::Op(s) => match s.as_str() {
                     "<" | "<=" | "<>" | "!=" | ">" | ">=" => Precedence::Cmp,
                     "+" | "-" => Precedence::PlusMinus,
@@ -1686,16 +1877,28 @@ impl<'a> Parser<'a> {
                 self.peek_pos(),
                 "CREATE USER is not supported, for more information consult the documentation at https://materialize.com/docs/sql/create-role/#details"
             )
+        } else if self.peek_keyword(AGGREGATE) {
+            parser_err!(
+                self,
+                self.peek_pos(),
+                "CREATE AGGREGATE is not supported, for more information consult the documentation at https://materialize.com/docs/sql/functions/#aggregate-functions"
+            )
         } else {
             let index = self.index;
 
             // go over optional modifiers
-            let _ = self.parse_keywords(&[OR, REPLACE]);
+            let or_replace = self.parse_keywords(&[OR, REPLACE]);
             let _ = self.parse_one_of_keywords(&[TEMP, TEMPORARY]);
 
             if self.parse_keyword(VIEW) {
                 self.index = index;
                 self.parse_create_view()
+            } else if or_replace {
+                parser_err!(
+                    self,
+                    self.peek_pos(),
+                    "OR REPLACE is only supported for views and materialized views"
+                )
             } else {
                 self.expected(
                     self.peek_pos(),
@@ -3504,6 +3707,32 @@ impl<'a> Parser<'a> {
             let expr = self.parse_expr()?;
             self.expect_token(&Token::RParen)?;
             ColumnOption::Check(expr)
+        } else if self.parse_keyword(GENERATED) {
+            let always = match self.expect_one_of_keywords(&[ALWAYS, BY])? {
+                ALWAYS => true,
+                BY => {
+                    self.expect_keyword(DEFAULT)?;
+                    false
+                }
+                _ => unreachable!(),
+            };
+            self.expect_keyword(AS)?;
+            if self.parse_keyword(IDENTITY) {
+                ColumnOption::Generated {
+                    always,
+                    expr: None,
+                    stored: false,
+                }
+            } else {
+                self.expect_token(&Token::LParen)?;
+                let expr = self.parse_expr()?;
+                self.expect_token(&Token::RParen)?;
+                ColumnOption::Generated {
+                    always,
+                    expr: Some(expr),
+                    stored: self.parse_keyword(STORED),
+                }
+            }
         } else {
             return self.expected(self.peek_pos(), "column option", self.peek_token());
         };
@@ -4163,10 +4392,24 @@ impl<'a> Parser<'a> {
                         "queries not allowed in COPY FROM"
                     );
                 }
+                if self.parse_keyword(PROGRAM) {
+                    return parser_err!(
+                        self,
+                        self.peek_prev_pos(),
+                        "COPY FROM PROGRAM is not supported"
+                    );
+                }
                 self.expect_keyword(STDIN)?;
                 (CopyDirection::From, CopyTarget::Stdin)
             }
             TO => {
+                if self.parse_keyword(PROGRAM) {
+                    return parser_err!(
+                        self,
+                        self.peek_prev_pos(),
+                        "COPY TO PROGRAM is not supported"
+                    );
+                }
                 self.expect_keyword(STDOUT)?;
                 (CopyDirection::To, CopyTarget::Stdout)
             }
@@ -4517,7 +4760,17 @@ impl<'a> Parser<'a> {
     fn parse_optional_table_alias(&mut self) -> Result<Option<TableAlias>, ParserError> {
         match self.parse_optional_alias(Keyword::is_reserved_in_table_alias)? {
             Some(name) => {
-                let columns = self.parse_parenthesized_column_list(Optional)?;
+                // Unlike other column lists, a table alias may specify an
+                // empty list of columns, e.g. `AS t ()`.
+                let columns = if self.peek_token() == Some(Token::LParen)
+                    && self.peek_nth_token(1) == Some(Token::RParen)
+                {
+                    self.next_token();
+                    self.next_token();
+                    vec![]
+                } else {
+                    self.parse_parenthesized_column_list(Optional)?
+                };
                 Ok(Some(TableAlias {
                     name,
                     columns,
@@ -4725,6 +4978,7 @@ impl<'a> Parser<'a> {
 
     fn parse_delete(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(FROM)?;
+        let only = self.parse_only_modifier();
         let table_name = RawItemName::Name(self.parse_item_name()?);
         let alias = self.parse_optional_table_alias()?;
         let using = if self.parse_keyword(USING) {
@@ -4732,13 +4986,10 @@ impl<'a> Parser<'a> {
         } else {
             vec![]
         };
-        let selection = if self.parse_keyword(WHERE) {
-            Some(self.parse_expr()?)
-        } else {
-            None
-        };
+        let selection = self.parse_optional_mutation_selection()?;
 
         Ok(Statement::Delete(DeleteStatement {
+            only,
             table_name,
             alias,
             using,
@@ -4966,7 +5217,7 @@ impl<'a> Parser<'a> {
     /// Parse a "query body", which is an expression with roughly the
     /// following grammar:
     /// ```text
-    ///   query_body ::= restricted_select | '(' subquery ')' | set_operation
+    ///   query_body ::= restricted_select | '(' subquery ')' | 'TABLE' name | set_operation
     ///   restricted_select ::= 'SELECT' [expr_list] [ from ] [ where ] [ groupby_having ]
     ///   subquery ::= query_body [ order_by_limit ]
     ///   set_operation ::= query_body { 'UNION' | 'EXCEPT' | 'INTERSECT' } [ 'ALL' ] query_body
@@ -4985,10 +5236,30 @@ impl<'a> Parser<'a> {
             SetExpr::Values(self.parse_values()?)
         } else if self.parse_keyword(SHOW) {
             SetExpr::Show(self.parse_show()?)
+        } else if self.parse_keyword(TABLE) {
+            // `TABLE name` is shorthand for `SELECT * FROM name`.
+            SetExpr::Select(Box::new(Select {
+                distinct: None,
+                projection: vec![SelectItem::Wildcard],
+                from: vec![TableWithJoins {
+                    relation: TableFactor::Table {
+                        only: false,
+                        name: self.parse_raw_name()?,
+                        alias: None,
+                        sample: None,
+                    },
+                    joins: vec![],
+                }],
+                selection: None,
+                group_by: vec![],
+                having: None,
+                windows: vec![],
+                options: vec![],
+            }))
         } else {
             return self.expected(
                 self.peek_pos(),
-                "SELECT, VALUES, or a subquery in the query body",
+                "SELECT, VALUES, TABLE, or a subquery in the query body",
                 self.peek_token(),
             );
         };
@@ -5096,7 +5367,7 @@ impl<'a> Parser<'a> {
         };
 
         let group_by = if self.parse_keywords(&[GROUP, BY]) {
-            self.parse_comma_separated(Parser::parse_expr)?
+            self.parse_comma_separated(Parser::parse_group_by_expr)?
         } else {
             vec![]
         };
@@ -5107,6 +5378,12 @@ impl<'a> Parser<'a> {
             None
         };
 
+        let windows = if self.parse_keyword(WINDOW) {
+            self.parse_comma_separated(Parser::parse_window_definition)?
+        } else {
+            vec![]
+        };
+
         let options = if self.parse_keyword(OPTIONS) {
             self.expect_token(&Token::LParen)?;
             let options = self.parse_comma_separated(Self::parse_select_option)?;
@@ -5123,10 +5400,52 @@ impl<'a> Parser<'a> {
             selection,
             group_by,
             having,
+            windows,
             options,
         })
     }
 
+    /// Parses an item in a `GROUP BY` clause.
+    fn parse_group_by_expr(&mut self) -> Result<GroupByExpr<Raw>, ParserError> {
+        if self.parse_keywords(&[GROUPING, SETS]) {
+            Ok(GroupByExpr::GroupingSets(self.parse_grouping_set_list()?))
+        } else if self.peek_keyword(CUBE) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.expect_keyword(CUBE)?;
+            Ok(GroupByExpr::Cube(self.parse_grouping_set_list()?))
+        } else if self.peek_keyword(ROLLUP) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.expect_keyword(ROLLUP)?;
+            Ok(GroupByExpr::Rollup(self.parse_grouping_set_list()?))
+        } else {
+            Ok(GroupByExpr::Expr(self.parse_expr()?))
+        }
+    }
+
+    /// Parses a parenthesized list of grouping sets, as in the argument to
+    /// `GROUPING SETS`, `CUBE`, or `ROLLUP`.
+    fn parse_grouping_set_list(&mut self) -> Result<Vec<Vec<Expr<Raw>>>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let sets = self.parse_comma_separated(|parser| {
+            if parser.peek_token() == Some(Token::LParen)
+                && parser.peek_nth_token(1) == Some(Token::RParen)
+            {
+                // The empty grouping set, `()`.
+                parser.next_token();
+                parser.next_token();
+                return Ok(vec![]);
+            }
+            // A parenthesized list like `(a, b)` parses as a row constructor
+            // and `(a)` as a nested expression; unwrap both into the list of
+            // expressions that make up the set.
+            Ok(match parser.parse_expr()? {
+                Expr::Row { exprs } => exprs,
+                Expr::Nested(expr) => vec![*expr],
+                expr => vec![expr],
+            })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(sets)
+    }
+
     fn parse_select_option(&mut self) -> Result<SelectOption<Raw>, ParserError> {
         self.expect_keywords(&[EXPECTED, GROUP, SIZE])?;
         let name = SelectOptionName::ExpectedGroupSize;
@@ -5139,6 +5458,13 @@ impl<'a> Parser<'a> {
     fn parse_set(&mut self) -> Result<Statement<Raw>, ParserError> {
         let modifier = self.parse_one_of_keywords(&[SESSION, LOCAL]);
         let mut variable = self.parse_identifier()?;
+        if self.parse_keywords(&[FROM, CURRENT]) {
+            return Ok(Statement::SetVariable(SetVariableStatement {
+                local: modifier == Some(LOCAL),
+                variable,
+                to: SetVariableTo::FromCurrent,
+            }));
+        }
         let mut normal = self.consume_token(&Token::Eq) || self.parse_keyword(TO);
         if !normal {
             match variable.as_str().parse() {
@@ -5472,6 +5798,7 @@ impl<'a> Parser<'a> {
                     function: Function {
                         name,
                         args,
+                        within_group: vec![],
                         filter: None,
                         over: None,
                         distinct: false,
@@ -5535,6 +5862,7 @@ impl<'a> Parser<'a> {
         } else if self.parse_keywords(&[ROWS, FROM]) {
             Ok(self.parse_rows_from()?)
         } else {
+            let only = self.parse_only_modifier();
             let name = self.parse_raw_name()?;
             if self.consume_token(&Token::LParen) {
                 let args = self.parse_optional_args(false)?;
@@ -5544,6 +5872,7 @@ impl<'a> Parser<'a> {
                     function: Function {
                         name,
                         args,
+                        within_group: vec![],
                         filter: None,
                         over: None,
                         distinct: false,
@@ -5553,13 +5882,76 @@ impl<'a> Parser<'a> {
                 })
             } else {
                 Ok(TableFactor::Table {
+                    only,
                     name,
                     alias: self.parse_optional_table_alias()?,
+                    sample: self.parse_optional_table_sample()?,
                 })
             }
         }
     }
 
+    /// Parses an optional `TABLESAMPLE {BERNOULLI | SYSTEM} (percent) [REPEATABLE (seed)]`
+    /// clause following a table name and its alias.
+    fn parse_optional_table_sample(&mut self) -> Result<Option<TableSample<Raw>>, ParserError> {
+        if !self.parse_keyword(TABLESAMPLE) {
+            return Ok(None);
+        }
+        let method = match self.expect_one_of_keywords(&[BERNOULLI, SYSTEM])? {
+            BERNOULLI => TableSampleMethod::Bernoulli,
+            SYSTEM => TableSampleMethod::System,
+            _ => unreachable!(),
+        };
+        self.expect_token(&Token::LParen)?;
+        let percent = self.parse_table_sample_argument()?;
+        self.expect_token(&Token::RParen)?;
+        let repeatable = if self.parse_keyword(REPEATABLE) {
+            self.expect_token(&Token::LParen)?;
+            let seed = self.parse_table_sample_argument()?;
+            self.expect_token(&Token::RParen)?;
+            Some(seed)
+        } else {
+            None
+        };
+        Ok(Some(TableSample {
+            method,
+            percent,
+            repeatable,
+        }))
+    }
+
+    /// Parses an argument to `TABLESAMPLE` or `REPEATABLE`, which must be a numeric literal
+    /// or a parameter.
+    fn parse_table_sample_argument(&mut self) -> Result<Expr<Raw>, ParserError> {
+        match self.peek_token() {
+            Some(Token::Parameter(n)) => {
+                self.next_token();
+                Ok(Expr::Parameter(n))
+            }
+            Some(Token::Number(_)) => Ok(Expr::Value(self.parse_number_value()?)),
+            other => self.expected(self.peek_pos(), "literal number or parameter", other),
+        }
+    }
+
+    /// Parses the `ONLY` that may precede a table name to exclude inheriting tables.
+    ///
+    /// `ONLY` is not a reserved keyword, so it is only a modifier when it is followed by
+    /// something that could be a table name. `FROM only` still refers to a table named `only`.
+    fn parse_only_modifier(&mut self) -> bool {
+        if !self.peek_keyword(ONLY) {
+            return false;
+        }
+        let only = match self.peek_nth_token(1) {
+            Some(Token::Ident(_)) => true,
+            Some(Token::Keyword(kw)) => kw != AS && !kw.is_reserved_in_table_alias(),
+            _ => false,
+        };
+        if only {
+            self.next_token();
+        }
+        only
+    }
+
     fn parse_rows_from(&mut self) -> Result<TableFactor<Raw>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let functions = self.parse_comma_separated(Parser::parse_named_function)?;
@@ -5657,11 +6049,7 @@ impl<'a> Parser<'a> {
 
         self.expect_keyword(SET)?;
         let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
-        let selection = if self.parse_keyword(WHERE) {
-            Some(self.parse_expr()?)
-        } else {
-            None
-        };
+        let selection = self.parse_optional_mutation_selection()?;
 
         Ok(Statement::Update(UpdateStatement {
             table_name,
@@ -5671,6 +6059,91 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse a `MERGE` statement, assuming that the `MERGE` token has already
+    /// been consumed.
+    fn parse_merge(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(INTO)?;
+        let table_name = RawItemName::Name(self.parse_item_name()?);
+        // As in UPDATE, the alias here doesn't support columns.
+        let alias = self.parse_optional_alias(Keyword::is_reserved_in_table_alias)?;
+        let alias = alias.map(|name| TableAlias {
+            name,
+            columns: Vec::new(),
+            strict: false,
+        });
+        self.expect_keyword(USING)?;
+        let source = self.parse_table_factor()?;
+        self.expect_keyword(ON)?;
+        let on = self.parse_expr()?;
+        let mut clauses = vec![self.parse_merge_clause()?];
+        while self.peek_keyword(WHEN) {
+            clauses.push(self.parse_merge_clause()?);
+        }
+        Ok(Statement::Merge(MergeStatement {
+            table_name,
+            alias,
+            source,
+            on,
+            clauses,
+        }))
+    }
+
+    /// Parse a `WHEN [NOT] MATCHED [AND <condition>] THEN <action>` clause of a
+    /// `MERGE` statement.
+    fn parse_merge_clause(&mut self) -> Result<MergeClause<Raw>, ParserError> {
+        self.expect_keyword(WHEN)?;
+        let matched = !self.parse_keyword(NOT);
+        self.expect_keyword(MATCHED)?;
+        let condition = if self.parse_keyword(AND) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        self.expect_keyword(THEN)?;
+        let action = if matched {
+            match self.expect_one_of_keywords(&[UPDATE, DELETE])? {
+                UPDATE => {
+                    self.expect_keyword(SET)?;
+                    MergeAction::Update(self.parse_comma_separated(Parser::parse_assignment)?)
+                }
+                DELETE => MergeAction::Delete,
+                _ => unreachable!(),
+            }
+        } else {
+            self.expect_keyword(INSERT)?;
+            let columns = self.parse_parenthesized_column_list(Optional)?;
+            let values = if self.parse_keywords(&[DEFAULT, VALUES]) {
+                None
+            } else {
+                self.expect_keyword(VALUES)?;
+                self.expect_token(&Token::LParen)?;
+                let values = self.parse_comma_separated(Parser::parse_expr)?;
+                self.expect_token(&Token::RParen)?;
+                Some(values)
+            };
+            MergeAction::Insert { columns, values }
+        };
+        Ok(MergeClause {
+            matched,
+            condition,
+            action,
+        })
+    }
+
+    /// Parse the optional `WHERE` clause of an UPDATE or DELETE statement
+    fn parse_optional_mutation_selection(
+        &mut self,
+    ) -> Result<Option<MutationSelection<Raw>>, ParserError> {
+        if !self.parse_keyword(WHERE) {
+            return Ok(None);
+        }
+        if self.parse_keywords(&[CURRENT, OF]) {
+            Ok(Some(MutationSelection::CurrentOf(self.parse_identifier()?)))
+        } else {
+            Ok(Some(MutationSelection::Expr(self.parse_expr()?)))
+        }
+    }
+
     /// Parse a `var = expr` assignment, used in an UPDATE statement
     fn parse_assignment(&mut self) -> Result<Assignment<Raw>, ParserError> {
         let id = self.parse_identifier()?;
@@ -5881,16 +6354,13 @@ impl<'a> Parser<'a> {
         } else {
             SubscribeRelation::Name(self.parse_raw_name()?)
         };
-        let options = if self.parse_keyword(WITH) {
-            self.expect_token(&Token::LParen)?;
-            let options = self.parse_comma_separated(Self::parse_subscribe_option)?;
-            self.expect_token(&Token::RParen)?;
-            options
-        } else {
-            vec![]
-        };
+        let mut options = self.parse_subscribe_options()?;
         let as_of = self.parse_optional_as_of()?;
         let up_to = self.parse_optional_up_to()?;
+        // The options may also follow the temporal bounds, but may only be specified once.
+        if options.is_empty() {
+            options = self.parse_subscribe_options()?;
+        }
         let output = if self.parse_keywords(&[ENVELOPE]) {
             let keyword = self.expect_one_of_keywords(&[UPSERT, DEBEZIUM])?;
             self.expect_token(&Token::LParen)?;
@@ -5919,6 +6389,17 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_subscribe_options(&mut self) -> Result<Vec<SubscribeOption<Raw>>, ParserError> {
+        if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Self::parse_subscribe_option)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(options)
+        } else {
+            Ok(vec![])
+        }
+    }
+
     fn parse_subscribe_option(&mut self) -> Result<SubscribeOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[PROGRESS, SNAPSHOT])? {
             PROGRESS => SubscribeOptionName::Progress,
@@ -5934,6 +6415,27 @@ impl<'a> Parser<'a> {
     /// Parse an `EXPLAIN` statement, assuming that the `EXPLAIN` token
     /// has already been consumed.
     fn parse_explain(&mut self) -> Result<Statement<Raw>, ParserError> {
+        // EXPLAIN (option [, ...]) explainee, unless the parenthesis opens a query.
+        let opens_options = match self.peek_nth_token(1) {
+            Some(Token::Keyword(SELECT | VALUES | WITH | TABLE | SHOW)) => false,
+            Some(Token::Keyword(_) | Token::Ident(_)) => true,
+            _ => false,
+        };
+        if opens_options && self.consume_token(&Token::LParen) {
+            let options = self.parse_comma_separated(Parser::parse_explain_option)?;
+            self.expect_token(&Token::RParen)?;
+            let no_errors = self.parse_keyword(BROKEN);
+            let explainee = self.parse_explainee()?;
+            return Ok(Statement::Explain(ExplainStatement {
+                stage: ExplainStage::OptimizedPlan,
+                config_flags: vec![],
+                format: ExplainFormat::Text,
+                options,
+                no_errors,
+                explainee,
+            }));
+        }
+
         let stage = match self.parse_one_of_keywords(&[
             RAW,
             DECORRELATED,
@@ -6000,25 +6502,45 @@ impl<'a> Parser<'a> {
         }
 
         let no_errors = self.parse_keyword(BROKEN);
-
-        // VIEW name | MATERIALIZED VIEW name | query
-        let explainee = if self.parse_keyword(VIEW) {
-            Explainee::View(self.parse_raw_name()?)
-        } else if self.parse_keywords(&[MATERIALIZED, VIEW]) {
-            Explainee::MaterializedView(self.parse_raw_name()?)
-        } else {
-            Explainee::Query(self.parse_query()?)
-        };
+        let explainee = self.parse_explainee()?;
 
         Ok(Statement::Explain(ExplainStatement {
             stage: stage.unwrap_or(ExplainStage::OptimizedPlan),
             config_flags,
             format,
+            options: vec![],
             no_errors,
             explainee,
         }))
     }
 
+    fn parse_explain_option(&mut self) -> Result<ExplainOption<Raw>, ParserError> {
+        let name = match self.parse_one_of_keywords(&[FORMAT, TYPE]) {
+            Some(FORMAT) => ExplainOptionName::Format,
+            Some(TYPE) => ExplainOptionName::Stage,
+            None => {
+                let pos = self.peek_pos();
+                let option = self.parse_identifier()?;
+                return parser_err!(self, pos, "unrecognized EXPLAIN option {}", option);
+            }
+            _ => unreachable!(),
+        };
+        let value = self.parse_optional_option_value()?;
+        Ok(ExplainOption { name, value })
+    }
+
+    /// Parses the explainee of an `EXPLAIN` statement: `VIEW name`, `MATERIALIZED VIEW name`, or a
+    /// query.
+    fn parse_explainee(&mut self) -> Result<Explainee<Raw>, ParserError> {
+        Ok(if self.parse_keyword(VIEW) {
+            Explainee::View(self.parse_raw_name()?)
+        } else if self.parse_keywords(&[MATERIALIZED, VIEW]) {
+            Explainee::MaterializedView(self.parse_raw_name()?)
+        } else {
+            Explainee::Query(self.parse_query()?)
+        })
+    }
+
     /// Parse a `DECLARE` statement, assuming that the `DECLARE` token
     /// has already been consumed.
     fn parse_declare(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -6139,6 +6661,77 @@ impl<'a> Parser<'a> {
         Ok(Statement::Raise(RaiseStatement { severity }))
     }
 
+    /// Parse a `CALL` statement, assuming that the `CALL` token
+    /// has already been consumed.
+    fn parse_call(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let name = self.parse_item_name()?;
+        self.expect_token(&Token::LParen)?;
+        let args = self.parse_optional_args(false)?;
+        Ok(Statement::Call(CallStatement { name, args }))
+    }
+
+    /// Parse an `ANALYZE` statement, assuming that the `ANALYZE` token
+    /// has already been consumed.
+    fn parse_analyze(&mut self) -> Result<Statement<Raw>, ParserError> {
+        Ok(Statement::Analyze(AnalyzeStatement {
+            tables: self.parse_optional_table_list()?,
+        }))
+    }
+
+    /// Parse a `VACUUM` statement, assuming that the `VACUUM` token
+    /// has already been consumed.
+    fn parse_vacuum(&mut self) -> Result<Statement<Raw>, ParserError> {
+        Ok(Statement::Vacuum(VacuumStatement {
+            tables: self.parse_optional_table_list()?,
+        }))
+    }
+
+    /// Parse a `COMMENT ON` statement, assuming that the `COMMENT` token
+    /// has already been consumed.
+    fn parse_comment(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(ON)?;
+        let object = if self.parse_keyword(COLUMN) {
+            let pos = self.peek_pos();
+            let mut name = self.parse_identifiers()?;
+            if name.len() < 2 {
+                return parser_err!(
+                    self,
+                    pos,
+                    "column name must be qualified with the name of its relation"
+                );
+            }
+            let column = name.pop().unwrap();
+            CommentObject::Column {
+                relation: UnresolvedItemName(name),
+                column,
+            }
+        } else {
+            let object_type = self.expect_object_type()?;
+            CommentObject::Object {
+                object_type,
+                name: self.parse_object_name(object_type)?,
+            }
+        };
+        self.expect_keyword(IS)?;
+        let comment = if self.parse_keyword(NULL) {
+            None
+        } else {
+            Some(self.parse_literal_string()?)
+        };
+        Ok(Statement::Comment(CommentStatement { object, comment }))
+    }
+
+    /// Parse a possibly empty comma-separated list of table names, as accepted
+    /// by `ANALYZE` and `VACUUM`.
+    fn parse_optional_table_list(&mut self) -> Result<Vec<RawItemName>, ParserError> {
+        match self.peek_token() {
+            Some(Token::Ident(_)) | Some(Token::Keyword(_)) => {
+                self.parse_comma_separated(Parser::parse_raw_name)
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
     /// Parse a `GRANT` statement, assuming that the `GRANT` token
     /// has already been consumed.
     fn parse_grant(&mut self) -> Result<Statement<Raw>, ParserError> {
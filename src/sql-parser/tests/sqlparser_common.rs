@@ -369,3 +369,22 @@ fn test_max_statement_batch_size() {
     assert!(err.contains("statement batch size cannot exceed "));
     assert!(parse_statements(&statements).is_ok());
 }
+
+#[mz_ore::test]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+fn test_error_span() {
+    let span = |sql: &str| {
+        let e = parse_statements(sql).expect_err("statement should not parse");
+        (e.pos, e.end)
+    };
+
+    // The error spans the whole offending token.
+    assert_eq!(span("SELECT 1 FROM foo bar baz"), (22, Some(25)));
+    assert_eq!(span("SELECT 1 FROM foo bar baz\n  ;"), (22, Some(25)));
+    assert_eq!(
+        span("SELECT * FROM t ORDER BY a DESC NULLS MIDDLE"),
+        (38, Some(44))
+    );
+    // There is no token to span at the end of the input.
+    assert_eq!(span("SELECT * FROM t WHERE"), (21, None));
+}
@@ -1664,8 +1664,13 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                 _ => self.fold_item_name(node.name),
             },
             args: self.fold_function_args(node.args),
+            within_group: node
+                .within_group
+                .into_iter()
+                .map(|obe| self.fold_order_by_expr(obe))
+                .collect(),
             filter: node.filter.map(|expr| Box::new(self.fold_expr(*expr))),
-            over: node.over.map(|over| self.fold_window_spec(over)),
+            over: node.over.map(|over| self.fold_window_type(over)),
             distinct: node.distinct,
         }
     }
@@ -1676,9 +1681,16 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
     ) -> mz_sql_parser::ast::TableFactor<Aug> {
         use mz_sql_parser::ast::TableFactor::*;
         match node {
-            Table { name, alias } => Table {
+            Table {
+                only,
+                name,
+                alias,
+                sample,
+            } => Table {
+                only,
                 name: self.fold_item_name(name),
                 alias: alias.map(|alias| self.fold_table_alias(alias)),
+                sample: sample.map(|sample| self.fold_table_sample(sample)),
             },
             Function {
                 function,
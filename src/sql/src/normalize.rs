@@ -225,18 +225,29 @@ pub fn create_statement(
                     }
                 }
             }
+            for expr in &mut func.within_group {
+                self.visit_order_by_expr_mut(expr);
+            }
             if let Some(over) = &mut func.over {
-                self.visit_window_spec_mut(over);
+                self.visit_window_type_mut(over);
             }
         }
 
         fn visit_table_factor_mut(&mut self, table_factor: &'ast mut TableFactor<Aug>) {
             match table_factor {
-                TableFactor::Table { name, alias, .. } => {
+                TableFactor::Table {
+                    name,
+                    alias,
+                    sample,
+                    ..
+                } => {
                     self.visit_item_name_mut(name);
                     if let Some(alias) = alias {
                         self.visit_table_alias_mut(alias);
                     }
+                    if let Some(sample) = sample {
+                        self.visit_table_sample_mut(sample);
+                    }
                 }
                 // We only need special behavior for `TableFactor::Table`.
                 // Just visit the other types of table factors like normal.
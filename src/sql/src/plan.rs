@@ -179,7 +179,10 @@ impl Plan {
             }
             StatementKind::AlterSystemSet => vec![PlanKind::AlterNoop, PlanKind::AlterSystemSet],
             StatementKind::AlterOwner => vec![PlanKind::AlterNoop, PlanKind::AlterOwner],
+            StatementKind::Analyze => vec![],
+            StatementKind::Call => vec![],
             StatementKind::Close => vec![PlanKind::Close],
+            StatementKind::Comment => vec![],
             StatementKind::Commit => vec![PlanKind::CommitTransaction],
             StatementKind::Copy => vec![PlanKind::CopyFrom, PlanKind::Peek, PlanKind::Subscribe],
             StatementKind::CreateCluster => vec![PlanKind::CreateCluster],
@@ -210,6 +213,7 @@ impl Plan {
             StatementKind::GrantPrivileges => vec![PlanKind::GrantPrivileges],
             StatementKind::GrantRole => vec![PlanKind::GrantRole],
             StatementKind::Insert => vec![PlanKind::Insert],
+            StatementKind::Merge => vec![],
             StatementKind::Prepare => vec![PlanKind::Prepare],
             StatementKind::Raise => vec![PlanKind::Raise],
             StatementKind::ReassignOwned => vec![PlanKind::ReassignOwned],
@@ -229,6 +233,7 @@ impl Plan {
             StatementKind::StartTransaction => vec![PlanKind::StartTransaction],
             StatementKind::Subscribe => vec![PlanKind::Subscribe],
             StatementKind::Update => vec![PlanKind::ReadThenWrite],
+            StatementKind::Vacuum => vec![],
         }
     }
 
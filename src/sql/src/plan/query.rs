@@ -56,11 +56,12 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     AsOf, Assignment, AstInfo, CteBlock, DeleteStatement, Distinct, Expr, Function, FunctionArgs,
-    HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join, JoinConstraint, JoinOperator,
-    Limit, MutRecBlock, MutRecBlockOption, MutRecBlockOptionName, OrderByExpr, Query, Select,
-    SelectItem, SelectOption, SelectOptionName, SetExpr, SetOperator, ShowStatement,
-    SubscriptPosition, TableAlias, TableFactor, TableWithJoins, UnresolvedItemName,
+    GroupByExpr, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join, JoinConstraint,
+    JoinOperator, Limit, MutRecBlock, MutRecBlockOption, MutRecBlockOptionName, MutationSelection,
+    OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName, SetExpr, SetOperator,
+    ShowStatement, SubscriptPosition, TableAlias, TableFactor, TableWithJoins, UnresolvedItemName,
     UpdateStatement, Value, Values, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    WindowType,
 };
 use uuid::Uuid;
 
@@ -565,8 +566,14 @@ pub fn plan_mutation_query_inner(
     alias: Option<TableAlias>,
     using: Vec<TableWithJoins<Aug>>,
     assignments: Vec<Assignment<Aug>>,
-    selection: Option<Expr<Aug>>,
+    selection: Option<MutationSelection<Aug>>,
 ) -> Result<ReadThenWritePlan, PlanError> {
+    let selection = match selection {
+        Some(MutationSelection::Expr(expr)) => Some(expr),
+        Some(MutationSelection::CurrentOf(_)) => bail_unsupported!("WHERE CURRENT OF"),
+        None => None,
+    };
+
     // Get global ID.
     let id = match table_name {
         ResolvedItemName::Item { id, .. } => id,
@@ -1708,6 +1715,10 @@ fn plan_view_select(
         seen: _,
     } = SelectOptionExtracted::try_from(s.options.clone())?;
 
+    if !s.windows.is_empty() {
+        bail_unsupported!("WINDOW clause");
+    }
+
     // Step 1. Handle FROM clause, including joins.
     let (mut relation_expr, mut from_scope) =
         s.from.iter().fold(Ok(plan_join_identity()), |l, twj| {
@@ -1805,6 +1816,12 @@ fn plan_view_select(
         let mut select_all_mapping = BTreeMap::new();
 
         for group_expr in &s.group_by {
+            let group_expr = match group_expr {
+                GroupByExpr::Expr(expr) => expr,
+                GroupByExpr::GroupingSets(_) => bail_unsupported!("GROUPING SETS"),
+                GroupByExpr::Cube(_) => bail_unsupported!("CUBE"),
+                GroupByExpr::Rollup(_) => bail_unsupported!("ROLLUP"),
+            };
             let (group_expr, expr) = plan_group_by_expr(ecx, group_expr, &projection)?;
             let new_column = group_key.len();
 
@@ -2320,7 +2337,15 @@ fn plan_table_factor(
     table_factor: &TableFactor<Aug>,
 ) -> Result<(HirRelationExpr, Scope), PlanError> {
     match table_factor {
-        TableFactor::Table { name, alias } => {
+        TableFactor::Table {
+            name,
+            alias,
+            sample,
+            ..
+        } => {
+            if sample.is_some() {
+                bail_unsupported!("TABLESAMPLE");
+            }
             let (expr, scope) = qcx.resolve_table_name(name.clone())?;
             let scope = plan_table_alias(scope, alias.as_ref())?;
             Ok((expr, scope))
@@ -2601,6 +2626,7 @@ fn plan_table_function_internal(
     Function {
         name,
         args,
+        within_group,
         filter,
         over,
         distinct,
@@ -2608,6 +2634,10 @@ fn plan_table_function_internal(
     with_ordinality: bool,
     table_name: Option<FullItemName>,
 ) -> Result<(HirRelationExpr, Scope), PlanError> {
+    assert!(
+        within_group.is_empty(),
+        "cannot parse table function with WITHIN GROUP"
+    );
     assert!(filter.is_none(), "cannot parse table function with FILTER");
     assert!(over.is_none(), "cannot parse table function with OVER");
     assert!(!*distinct, "cannot parse table function with DISTINCT");
@@ -3332,6 +3362,7 @@ fn plan_expr_inner<'a>(
             *negated,
         )?
         .into()),
+        Expr::SimilarTo { .. } => bail_unsupported!("SIMILAR TO"),
 
         Expr::InList {
             expr,
@@ -4210,6 +4241,7 @@ fn plan_aggregate(
     Function::<Aug> {
         name,
         args,
+        within_group,
         filter,
         over,
         distinct,
@@ -4237,6 +4269,10 @@ fn plan_aggregate(
         bail_unsupported!("aggregate window functions");
     }
 
+    if !within_group.is_empty() {
+        bail_unsupported!("WITHIN GROUP");
+    }
+
     // We follow PostgreSQL's rule here for mapping `count(*)` into the
     // generalized function selection framework. The rule is simple: the user
     // must type `count(*)`, but the function selection framework sees an empty
@@ -4424,6 +4460,7 @@ fn plan_function<'a>(
     f @ Function {
         name,
         args,
+        within_group,
         filter,
         over,
         distinct,
@@ -4512,6 +4549,15 @@ fn plan_function<'a>(
                 .expect("already resolved")
         );
     }
+    if !within_group.is_empty() {
+        sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            ecx.qcx
+                .scx
+                .humanize_resolved_name(name)
+                .expect("already resolved")
+        );
+    }
 
     let scalar_args = match &args {
         FunctionArgs::Star => {
@@ -4626,6 +4672,7 @@ fn plan_is_expr<'a>(
                 }),
             }
         }
+        IsExprConstruct::Json { .. } => bail_unsupported!("IS JSON"),
     };
     if not {
         expr = expr.not();
@@ -4724,6 +4771,7 @@ fn validate_window_function_plan<'a>(
     Function {
         name,
         args,
+        within_group,
         filter,
         over,
         distinct,
@@ -4758,10 +4806,21 @@ fn validate_window_function_plan<'a>(
         bail_unsupported!("FILTER in non-aggregate window functions");
     }
 
+    if !within_group.is_empty() {
+        sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            name
+        );
+    }
+
     let window_spec = match over.as_ref() {
-        Some(over) => over,
+        Some(WindowType::Spec(spec)) => spec,
+        Some(WindowType::Named(_)) => bail_unsupported!("named windows"),
         None => sql_bail!("window function {} requires an OVER clause", name),
     };
+    if window_spec.window_name.is_some() {
+        bail_unsupported!("named windows");
+    }
     let window_frame = match window_spec.window_frame.as_ref() {
         Some(frame) => plan_window_frame(frame)?,
         None => mz_expr::WindowFrame::default(),
@@ -5149,6 +5208,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 let Function {
                     name: _,
                     args,
+                    within_group,
                     filter,
                     over: _,
                     distinct: _,
@@ -5162,6 +5222,9 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                     .push("aggregate function calls");
 
                 self.visit_function_args_mut(args);
+                for obe in within_group {
+                    self.visit_order_by_expr_mut(obe);
+                }
 
                 self.within_aggregate = old_within_aggregate;
                 self.table_disallowed_context.pop();
@@ -5216,17 +5279,20 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
             if let Function {
                 name: _,
                 args: _,
+                within_group,
                 filter: None,
                 over: None,
                 distinct: false,
             } = &func
             {
-                // Identical table functions can be de-duplicated.
-                let id = self
-                    .tables
-                    .entry(func)
-                    .or_insert_with(|| format!("table_func_{}", Uuid::new_v4()));
-                *expr = Expr::Identifier(vec![Ident::from(id.clone())]);
+                if within_group.is_empty() {
+                    // Identical table functions can be de-duplicated.
+                    let id = self
+                        .tables
+                        .entry(func)
+                        .or_insert_with(|| format!("table_func_{}", Uuid::new_v4()));
+                    *expr = Expr::Identifier(vec![Ident::from(id.clone())]);
+                }
             }
         }
         if let Some(context) = disallowed_context {
@@ -39,6 +39,7 @@ use crate::session::vars::FeatureFlag;
 mod acl;
 pub(crate) mod ddl;
 mod dml;
+mod maintenance;
 mod raise;
 mod scl;
 pub(crate) mod show;
@@ -198,10 +199,12 @@ pub fn describe(
         }
 
         // DML statements.
+        Statement::Call(stmt) => dml::describe_call(&scx, stmt)?,
         Statement::Copy(stmt) => dml::describe_copy(&scx, stmt)?,
         Statement::Delete(stmt) => dml::describe_delete(&scx, stmt)?,
         Statement::Explain(stmt) => dml::describe_explain(&scx, stmt)?,
         Statement::Insert(stmt) => dml::describe_insert(&scx, stmt)?,
+        Statement::Merge(stmt) => dml::describe_merge(&scx, stmt)?,
         Statement::Select(stmt) => dml::describe_select(&scx, stmt)?,
         Statement::Subscribe(stmt) => dml::describe_subscribe(&scx, stmt)?,
         Statement::Update(stmt) => dml::describe_update(&scx, stmt)?,
@@ -213,7 +216,10 @@ pub fn describe(
         Statement::StartTransaction(stmt) => tcl::describe_start_transaction(&scx, stmt)?,
 
         // Other statements.
+        Statement::Analyze(stmt) => maintenance::describe_analyze(&scx, stmt)?,
         Statement::Raise(stmt) => raise::describe_raise(&scx, stmt)?,
+        Statement::Vacuum(stmt) => maintenance::describe_vacuum(&scx, stmt)?,
+        Statement::Comment(stmt) => ddl::describe_comment(&scx, stmt)?,
     };
 
     let desc = desc.with_params(scx.finalize_param_types()?);
@@ -295,10 +301,12 @@ pub fn plan(
         Statement::ReassignOwned(stmt) => acl::plan_reassign_owned(scx, stmt),
 
         // DML statements.
+        Statement::Call(stmt) => dml::plan_call(scx, stmt),
         Statement::Copy(stmt) => dml::plan_copy(scx, stmt),
         Statement::Delete(stmt) => dml::plan_delete(scx, stmt, params),
         Statement::Explain(stmt) => dml::plan_explain(scx, stmt, params),
         Statement::Insert(stmt) => dml::plan_insert(scx, stmt, params),
+        Statement::Merge(stmt) => dml::plan_merge(scx, stmt),
         Statement::Select(stmt) => dml::plan_select(scx, stmt, params, None),
         Statement::Subscribe(stmt) => dml::plan_subscribe(scx, stmt, None),
         Statement::Update(stmt) => dml::plan_update(scx, stmt, params),
@@ -347,7 +355,10 @@ pub fn plan(
         Statement::StartTransaction(stmt) => tcl::plan_start_transaction(scx, stmt),
 
         // Other statements.
+        Statement::Analyze(stmt) => maintenance::plan_analyze(scx, stmt),
         Statement::Raise(stmt) => raise::plan_raise(scx, stmt),
+        Statement::Vacuum(stmt) => maintenance::plan_vacuum(scx, stmt),
+        Statement::Comment(stmt) => ddl::plan_comment(scx, stmt),
     };
 
     if let Ok(plan) = &plan {
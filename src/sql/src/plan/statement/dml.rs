@@ -26,11 +26,11 @@ use mz_sql_parser::ast::{Expr, OrderByExpr, SubscribeOutput};
 
 use crate::ast::display::AstDisplay;
 use crate::ast::{
-    AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
-    CreateMaterializedViewStatement, CreateViewStatement, DeleteStatement, ExplainStage,
-    ExplainStatement, Explainee, Ident, InsertStatement, Query, SelectStatement, Statement,
-    SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement, UpdateStatement,
-    ViewDefinition,
+    AstInfo, CallStatement, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement,
+    CopyTarget, CreateMaterializedViewStatement, CreateViewStatement, DeleteStatement,
+    ExplainOption, ExplainOptionName, ExplainStage, ExplainStatement, Explainee, Ident,
+    InsertStatement, MergeStatement, Query, SelectStatement, Statement, SubscribeOption,
+    SubscribeOptionName, SubscribeRelation, SubscribeStatement, UpdateStatement, ViewDefinition,
 };
 use crate::catalog::CatalogItemType;
 use crate::names::{self, Aug, ResolvedItemName};
@@ -130,6 +130,17 @@ pub fn plan_update(
     plan_read_then_write(MutationKind::Update, scx, params, rtw_plan)
 }
 
+pub fn describe_merge(
+    _: &StatementContext,
+    _: MergeStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    bail_unsupported!("MERGE")
+}
+
+pub fn plan_merge(_: &StatementContext, _: MergeStatement<Aug>) -> Result<Plan, PlanError> {
+    bail_unsupported!("MERGE")
+}
+
 pub fn plan_read_then_write(
     kind: MutationKind,
     scx: &StatementContext,
@@ -187,12 +198,49 @@ pub fn plan_select(
     }))
 }
 
+generate_extracted_config!(ExplainOption, (Format, String), (Stage, String));
+
+/// Applies the options of the parenthesized `EXPLAIN (...)` syntax on top of the stage and format
+/// of the `EXPLAIN ... FOR` syntax.
+fn apply_explain_options(
+    stage: ExplainStage,
+    format: mz_sql_parser::ast::ExplainFormat,
+    options: Vec<ExplainOption<Aug>>,
+) -> Result<(ExplainStage, mz_sql_parser::ast::ExplainFormat), PlanError> {
+    let options = ExplainOptionExtracted::try_from(options)?;
+    let stage = match options.stage {
+        None => stage,
+        Some(s) => match s.to_lowercase().as_str() {
+            "raw" => ExplainStage::RawPlan,
+            "decorrelated" => ExplainStage::DecorrelatedPlan,
+            "optimized" => ExplainStage::OptimizedPlan,
+            "physical" => ExplainStage::PhysicalPlan,
+            _ => sql_bail!("unknown TYPE: {}", s),
+        },
+    };
+    let format = match options.format {
+        None => format,
+        Some(f) => match f.to_lowercase().as_str() {
+            "text" => mz_sql_parser::ast::ExplainFormat::Text,
+            "json" => mz_sql_parser::ast::ExplainFormat::Json,
+            "dot" => mz_sql_parser::ast::ExplainFormat::Dot,
+            _ => sql_bail!("unknown FORMAT: {}", f),
+        },
+    };
+    Ok((stage, format))
+}
+
 pub fn describe_explain(
     scx: &StatementContext,
     ExplainStatement {
-        stage, explainee, ..
+        stage,
+        format,
+        options,
+        explainee,
+        ..
     }: ExplainStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
+    let (stage, _format) = apply_explain_options(stage, format, options)?;
     let mut relation_desc = RelationDesc::empty();
 
     match stage {
@@ -247,11 +295,13 @@ pub fn plan_explain(
         stage,
         config_flags,
         format,
+        options,
         no_errors,
         explainee,
     }: ExplainStatement<Aug>,
     params: &Params,
 ) -> Result<Plan, PlanError> {
+    let (stage, format) = apply_explain_options(stage, format, options)?;
     let is_view = matches!(explainee, Explainee::View(_));
     let (explainee, query) = match explainee {
         Explainee::View(name) => {
@@ -638,6 +688,17 @@ pub fn describe_table(
     Ok(StatementDesc::new(Some(desc)))
 }
 
+pub fn describe_call(
+    _: &StatementContext,
+    _: CallStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    bail_unsupported!("CALL")
+}
+
+pub fn plan_call(_: &StatementContext, _: CallStatement<Aug>) -> Result<Plan, PlanError> {
+    bail_unsupported!("CALL")
+}
+
 pub fn describe_copy(
     scx: &StatementContext,
     CopyStatement { relation, .. }: CopyStatement<Aug>,
@@ -0,0 +1,41 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Database maintenance statements.
+//!
+//! This module houses the handlers for statements that PostgreSQL uses to
+//! maintain tables, like `ANALYZE` and `VACUUM`. They are recognized so that
+//! migration scripts produce a clear error rather than a syntax error.
+
+use crate::ast::{AnalyzeStatement, VacuumStatement};
+use crate::names::Aug;
+use crate::plan::statement::{StatementContext, StatementDesc};
+use crate::plan::{Plan, PlanError};
+
+pub fn describe_analyze(
+    _: &StatementContext,
+    _: AnalyzeStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    bail_unsupported!("ANALYZE")
+}
+
+pub fn plan_analyze(_: &StatementContext, _: AnalyzeStatement<Aug>) -> Result<Plan, PlanError> {
+    bail_unsupported!("ANALYZE")
+}
+
+pub fn describe_vacuum(
+    _: &StatementContext,
+    _: VacuumStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    bail_unsupported!("VACUUM")
+}
+
+pub fn plan_vacuum(_: &StatementContext, _: VacuumStatement<Aug>) -> Result<Plan, PlanError> {
+    bail_unsupported!("VACUUM")
+}
@@ -70,6 +70,7 @@ pub fn plan_set_variable_to(to: SetVariableTo) -> Result<VariableValue, PlanErro
                 .collect();
             Ok(VariableValue::Values(values))
         }
+        SetVariableTo::FromCurrent => bail_unsupported!("SET ... FROM CURRENT"),
     }
 }
 
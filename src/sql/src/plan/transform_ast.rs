@@ -121,6 +121,7 @@ impl<'a> FuncRewriter<'a> {
                 args: vec![expr],
                 order_by,
             },
+            within_group: vec![],
             filter,
             over: None,
             distinct,
@@ -294,10 +295,11 @@ impl<'a> FuncRewriter<'a> {
             Expr::Function(Function {
                 name,
                 args: FunctionArgs::Args { args, order_by: _ },
+                within_group,
                 filter,
                 distinct,
                 over: None,
-            }) => {
+            }) if within_group.is_empty() => {
                 let name = match name {
                     ResolvedItemName::Item {
                         qualifiers,
@@ -507,6 +509,7 @@ impl<'a> Desugarer<'a> {
                                     .scx
                                     .dangerous_resolve_name(vec![MZ_CATALOG_SCHEMA, "unnest"]),
                                 args: FunctionArgs::args(vec![right.take()]),
+                                within_group: vec![],
                                 filter: None,
                                 over: None,
                                 distinct: false,